@@ -0,0 +1,48 @@
+//! Times the sparse Cholesky factorization of the 5-point Laplacian under
+//! each fill-reducing ordering. Run with `cargo bench --bench ordering`.
+
+use mermaid::sparse::{coo::CooMatrix, csc::CscMatrix, ordering::FillOrdering};
+use std::time::Instant;
+
+fn poisson(size: usize) -> CscMatrix<f64> {
+    let n = size * size;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..size {
+        for j in 0..size {
+            let k = i * size + j;
+            coo.push(k, k, 4.0);
+            if i > 0 {
+                coo.push(k, k - size, -1.0);
+            }
+            if i + 1 < size {
+                coo.push(k, k + size, -1.0);
+            }
+            if j > 0 {
+                coo.push(k, k - 1, -1.0);
+            }
+            if j + 1 < size {
+                coo.push(k, k + 1, -1.0);
+            }
+        }
+    }
+
+    CscMatrix::from(coo)
+}
+
+fn main() {
+    for size in [50, 100, 150] {
+        let a = poisson(size);
+        for ordering in [FillOrdering::Natural, FillOrdering::MinimumDegree] {
+            let start = Instant::now();
+            let nnz = a.cholesky(ordering).unwrap().l().nnz();
+            println!(
+                "{}x{} grid, {:?}: {} entries in L, {:?}",
+                size,
+                size,
+                ordering,
+                nnz,
+                start.elapsed()
+            );
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::identity::{IdAdd, IdMul};
+use std::fmt::{self, Display, Formatter};
+
+mod ops;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> Default for Complex<T>
+where
+    T: IdAdd,
+{
+    fn default() -> Self {
+        Self::id_add()
+    }
+}
+
+impl<T> IdAdd for Complex<T>
+where
+    T: IdAdd,
+{
+    fn id_add() -> Self {
+        Self::new(T::id_add(), T::id_add())
+    }
+}
+
+impl<T> IdMul for Complex<T>
+where
+    T: IdAdd + IdMul,
+{
+    fn id_mul() -> Self {
+        Self::new(T::id_mul(), T::id_add())
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: IdAdd,
+{
+    fn from(value: T) -> Self {
+        Self::new(value, T::id_add())
+    }
+}
+
+impl<T> Display for Complex<T>
+where
+    T: Display + PartialOrd + IdAdd,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.im < T::id_add() {
+            write!(f, "{}{}i", self.re, self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!("1.5+2i", Complex::new(1.5, 2.0).to_string());
+        assert_eq!("1.5-2i", Complex::new(1.5, -2.0).to_string());
+    }
+}
@@ -0,0 +1,138 @@
+use super::Complex;
+use crate::scalar::{Real, Scalar};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+impl<T> Complex<T>
+where
+    T: Scalar,
+{
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn abs_sq(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Real,
+{
+    pub fn abs(self) -> T {
+        self.abs_sq().sqrt()
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let num = self * rhs.conj();
+        let den = rhs.abs_sq();
+
+        Self::new(num.re / den, num.im / den)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conj() {
+        assert_eq!(Complex::new(3.0, -4.0), Complex::new(3.0, 4.0).conj());
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(5.0, Complex::new(3.0, 4.0).abs());
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            Complex::new(4.0, 1.0),
+            Complex::new(1.0, 3.0) + Complex::new(3.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(
+            Complex::new(-2.0, 5.0),
+            Complex::new(1.0, 3.0) - Complex::new(3.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(Complex::new(-1.0, 3.0), -Complex::new(1.0, -3.0));
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(
+            Complex::new(9.0, 7.0),
+            Complex::new(1.0, 3.0) * Complex::new(3.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(
+            Complex::new(1.0, 3.0),
+            Complex::new(9.0, 7.0) / Complex::new(3.0, -2.0)
+        );
+    }
+}
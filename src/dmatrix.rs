@@ -0,0 +1,415 @@
+use crate::{
+    identity::{IdAdd, IdMul},
+    matrix::Matrix,
+};
+use std::{
+    array,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    mem,
+};
+
+mod cmp;
+mod iter;
+mod ops;
+#[cfg(feature = "rayon")]
+mod par;
+mod view;
+
+pub use view::{DMatrixView, DMatrixViewMut};
+
+/// Heap-allocated matrix whose shape is only known at runtime, stored in row-major order.
+#[derive(Debug, Clone)]
+pub struct DMatrix<T> {
+    data: Vec<T>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T> DMatrix<T> {
+    pub fn from_vec(nrows: usize, ncols: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            nrows * ncols,
+            data.len(),
+            "cannot fill a {}x{} matrix with {} elements",
+            nrows,
+            ncols,
+            data.len()
+        );
+
+        Self { data, nrows, ncols }
+    }
+
+    pub fn from_fn<F>(nrows: usize, ncols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                data.push(f(i, j));
+            }
+        }
+
+        Self { data, nrows, ncols }
+    }
+
+    pub fn from_rows<R>(rows: Vec<R>) -> Self
+    where
+        R: Into<Vec<T>>,
+    {
+        let nrows = rows.len();
+        let mut ncols = None;
+        let mut data = Vec::new();
+        for row in rows {
+            let row = row.into();
+            let n = *ncols.get_or_insert(row.len());
+            assert_eq!(n, row.len(), "cannot build a matrix from ragged rows");
+            data.extend(row);
+        }
+
+        Self {
+            data,
+            nrows,
+            ncols: ncols.unwrap_or_default(),
+        }
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn row(&self, i: usize) -> Option<&[T]> {
+        (i < self.nrows).then(|| &self.data[i * self.ncols..(i + 1) * self.ncols])
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> Option<&mut [T]> {
+        (i < self.nrows).then(|| &mut self.data[i * self.ncols..(i + 1) * self.ncols])
+    }
+
+    pub fn col(&self, j: usize) -> Option<Vec<&T>> {
+        (j < self.ncols).then(|| (0..self.nrows).map(|i| &self[(i, j)]).collect())
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> Option<Vec<&mut T>> {
+        let ncols = self.ncols;
+        (j < ncols).then(|| self.data.iter_mut().skip(j).step_by(ncols).collect())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        let ncols = self.ncols;
+        (0..self.nrows).map(move |i| &self.data[i * ncols..(i + 1) * ncols])
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        // Splitting off one row at a time still yields `nrows` empty rows
+        // when there are no columns, unlike `chunks_mut`.
+        let ncols = self.ncols;
+        let mut rest = self.data.as_mut_slice();
+        (0..self.nrows).map(move |_| {
+            let (row, tail) = mem::take(&mut rest).split_at_mut(ncols);
+            rest = tail;
+            row
+        })
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        (i < self.nrows && j < self.ncols).then(|| &self.data[i * self.ncols + j])
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        (i < self.nrows && j < self.ncols).then(|| &mut self.data[i * self.ncols + j])
+    }
+
+    pub fn take(self, i: usize, j: usize) -> Option<T> {
+        let ncols = self.ncols;
+        (i < self.nrows && j < ncols).then(|| self.data.into_iter().nth(i * ncols + j))?
+    }
+
+    pub fn transpose(self) -> Self {
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        let mut cells: Vec<Option<T>> = self.data.into_iter().map(Some).collect();
+
+        Self::from_fn(ncols, nrows, |i, j| {
+            cells[j * ncols + i]
+                .take()
+                .expect("each element is moved exactly once")
+        })
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> DMatrix<U>
+    where
+        F: FnMut(usize, usize, &T) -> U,
+    {
+        DMatrix::from_fn(self.nrows, self.ncols, |i, j| f(i, j, &self[(i, j)]))
+    }
+
+    pub fn map_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        let ncols = self.ncols;
+        for (k, x) in self.data.iter_mut().enumerate() {
+            f(k / ncols, k % ncols, x);
+        }
+    }
+
+    pub fn into_map<U, F>(self, mut f: F) -> DMatrix<U>
+    where
+        F: FnMut(usize, usize, T) -> U,
+    {
+        let ncols = self.ncols;
+        DMatrix {
+            data: self
+                .data
+                .into_iter()
+                .enumerate()
+                .map(|(k, x)| f(k / ncols, k % ncols, x))
+                .collect(),
+            nrows: self.nrows,
+            ncols,
+        }
+    }
+
+    pub fn diag(&self) -> Vec<&T> {
+        (0..self.nrows.min(self.ncols))
+            .map(|i| &self[(i, i)])
+            .collect()
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: IdAdd,
+{
+    pub fn id_add(nrows: usize, ncols: usize) -> Self {
+        Self::from_fn(nrows, ncols, |_, _| T::id_add())
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: IdAdd + IdMul,
+{
+    pub fn id_mul(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::id_mul() } else { T::id_add() })
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for DMatrix<T> {
+    fn from(value: Matrix<T, M, N>) -> Self {
+        Self {
+            data: value.into_iter().collect(),
+            nrows: M,
+            ncols: N,
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<DMatrix<T>> for Matrix<T, M, N> {
+    type Error = ShapeError;
+
+    fn try_from(value: DMatrix<T>) -> Result<Self, Self::Error> {
+        if value.shape() != (M, N) {
+            return Err(ShapeError {
+                expected: (M, N),
+                found: value.shape(),
+            });
+        }
+
+        let mut data = value.data.into_iter();
+        Ok(Matrix::from_rows(array::from_fn(|_| {
+            array::from_fn(|_| data.next().expect("shape was checked"))
+        })))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeError {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a {}x{} matrix but found a {}x{} matrix",
+            self.expected.0, self.expected.1, self.found.0, self.found.1
+        )
+    }
+}
+
+impl Error for ShapeError {}
+
+impl<T> Display for DMatrix<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let strings = self.map(|_, _, x| x.to_string());
+        let max_len = strings.iter().map(|x| x.len()).max().unwrap_or_default() + 2;
+
+        writeln!(f, "┌{}┐", " ".repeat(self.ncols * max_len))?;
+        for row in strings.rows() {
+            write!(f, "│")?;
+            for x in row {
+                write!(f, "{: ^len$}", x, len = max_len)?;
+            }
+            writeln!(f, "│")?;
+        }
+        write!(f, "└{}┘", " ".repeat(self.ncols * max_len))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_rows() {
+        let mat = DMatrix::from_rows(vec![[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!((2, 3), mat.shape());
+        assert_eq!(DMatrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]), mat);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot fill a 2x2 matrix with 3 elements")]
+    fn from_vec_mismatch() {
+        let _ = DMatrix::from_vec(2, 2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn row_mut() {
+        let mut mat = DMatrix::from_rows(vec![[5, -1, 2], [-5, 0, -1]]);
+
+        mat.row_mut(0).unwrap()[2] = 8;
+        mat.row_mut(1).unwrap()[0] = 3;
+
+        assert_eq!(&8, &mat[(0, 2)]);
+        assert_eq!(&3, &mat[(1, 0)]);
+        assert_eq!(None, mat.row(2));
+    }
+
+    #[test]
+    fn col_mut() {
+        let mut mat = DMatrix::from_rows(vec![[5, -1, 2], [-5, 0, -1]]);
+
+        *mat.col_mut(1).unwrap()[1] = 7;
+
+        assert_eq!(Some(vec![&-1, &7]), mat.col(1));
+        assert_eq!(None, mat.col(3));
+    }
+
+    #[test]
+    fn rows() {
+        let mut mat = DMatrix::from_rows(vec![[5, -1, 2], [-5, 0, -1]]);
+        mat.rows_mut().for_each(|row| row.reverse());
+
+        assert_eq!(
+            vec![&[2, -1, 5][..], &[-1, 0, -5][..]],
+            mat.rows().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rows_without_cols() {
+        let mut mat = DMatrix::<i32>::from_vec(3, 0, vec![]);
+
+        assert_eq!(vec![&[] as &[i32]; 3], mat.rows().collect::<Vec<_>>());
+        assert_eq!(3, mat.rows_mut().count());
+        assert_eq!(0, DMatrix::<i32>::from_vec(0, 3, vec![]).rows().count());
+    }
+
+    #[test]
+    fn take() {
+        let mat = DMatrix::from_rows(vec![[4, -1, 3, 1], [-5, 8, 2, 0], [-3, -2, 1, 1]]);
+
+        assert_eq!(Some(3), mat.clone().take(0, 2));
+        assert_eq!(Some(1), mat.clone().take(2, 3));
+        assert_eq!(None, mat.clone().take(0, 4));
+        assert_eq!(None, mat.take(3, 2));
+    }
+
+    #[test]
+    fn transpose() {
+        let mat = DMatrix::from_rows(vec![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            DMatrix::from_rows(vec![[1, 4], [2, 5], [3, 6]]),
+            mat.transpose()
+        );
+    }
+
+    #[test]
+    fn into_map() {
+        let mat = DMatrix::from_rows(vec![[4, -1], [-5, 8]]);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![[4, -1], [-10, 16]]),
+            mat.into_map(|i, _, x| (i as i32 + 1) * x)
+        );
+    }
+
+    #[test]
+    fn id_mul() {
+        assert_eq!(
+            DMatrix::from_rows(vec![[1, 0, 0], [0, 1, 0], [0, 0, 1]]),
+            DMatrix::<u32>::id_mul(3)
+        );
+    }
+
+    #[test]
+    fn convert() {
+        let mat = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let dmat = DMatrix::from(mat.clone());
+
+        assert_eq!(DMatrix::from_rows(vec![[1, 2, 3], [4, 5, 6]]), dmat);
+        assert_eq!(Ok(mat), dmat.clone().try_into());
+        assert_eq!(
+            Err(ShapeError {
+                expected: (3, 2),
+                found: (2, 3)
+            }),
+            Matrix::<i32, 3, 2>::try_from(dmat)
+        );
+    }
+
+    #[test]
+    fn display() {
+        let mat = DMatrix::from_rows(vec![[-1, 0], [2, 87], [-55, 3]]);
+
+        assert_eq!(
+            "\
+            ┌          ┐\n\
+            │ -1    0  │\n\
+            │  2   87  │\n\
+            │ -55   3  │\n\
+            └          ┘\
+            ",
+            mat.to_string()
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::DMatrix;
+
+impl<T> PartialEq for DMatrix<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.data == other.data
+    }
+}
+
+impl<T> Eq for DMatrix<T> where T: Eq {}
@@ -0,0 +1,93 @@
+use super::DMatrix;
+use std::{slice, vec};
+
+impl<T> DMatrix<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over the elements in row-major order along with their indices.
+    pub fn cursor(&self) -> impl ExactSizeIterator<Item = (usize, usize, &T)> {
+        let ncols = self.ncols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, x)| (k / ncols, k % ncols, x))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DMatrix<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DMatrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for DMatrix<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mat = DMatrix::from_rows(vec![[1, 2], [3, 4], [5, 6]]);
+
+        let mut iter = mat.iter();
+        assert_eq!(6, iter.len());
+        assert_eq!(Some(&1), iter.next());
+        assert_eq!(vec![&2, &3, &4, &5, &6], iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut mat = DMatrix::from_rows(vec![[1, 2], [3, 4]]);
+
+        mat.iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(DMatrix::from_rows(vec![[2, 4], [6, 8]]), mat);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mat = DMatrix::from_rows(vec![[1, 2], [3, 4]]);
+        assert_eq!(vec![1, 2, 3, 4], mat.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn cursor() {
+        let mat = DMatrix::from_rows(vec![[1, 2], [3, 4], [5, 6]]);
+
+        let mut iter = mat.cursor();
+        assert_eq!(6, iter.len());
+        assert_eq!(Some((0, 0, &1)), iter.next());
+        assert_eq!(Some((0, 1, &2)), iter.next());
+        assert_eq!(Some((1, 0, &3)), iter.next());
+        assert_eq!(Some((1, 1, &4)), iter.next());
+        assert_eq!(Some((2, 0, &5)), iter.next());
+        assert_eq!(Some((2, 1, &6)), iter.next());
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+    }
+}
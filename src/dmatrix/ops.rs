@@ -0,0 +1,266 @@
+use super::DMatrix;
+use crate::{identity::IdAdd, matrix::gemm, scalar::Scalar};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<T> DMatrix<T> {
+    pub fn scale<U, V>(&self, factor: U) -> DMatrix<V>
+    where
+        for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+    {
+        self.map(|_, _, x| x * &factor)
+    }
+
+    pub fn scale_mut<U>(&mut self, factor: U)
+    where
+        for<'a> T: MulAssign<&'a U>,
+    {
+        self.map_mut(|_, _, x| *x *= &factor);
+    }
+
+    pub(super) fn assert_same_shape<U>(&self, rhs: &DMatrix<U>, op: &str) {
+        assert!(
+            self.shape() == rhs.shape(),
+            "cannot {} a {}x{} matrix and a {}x{} matrix",
+            op,
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Scalar + 'static,
+{
+    /// Computes `self * rhs`, handing large `f32` and `f64` products to a
+    /// blocked SIMD kernel. The `*` operator stays generic over any element
+    /// type and always takes the portable path.
+    pub fn matmul(&self, rhs: &DMatrix<T>) -> DMatrix<T> {
+        assert!(
+            self.ncols == rhs.nrows,
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+
+        gemm::try_mul_dmatrix(self, rhs).unwrap_or_else(|| {
+            DMatrix::from_fn(self.nrows, rhs.ncols, |i, j| {
+                (0..self.ncols).fold(T::id_add(), |x, k| x + self[(i, k)] * rhs[(k, j)])
+            })
+        })
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    for<'a> T: Add<&'a T, Output = T>,
+    T: IdAdd,
+{
+    pub fn trace(&self) -> T {
+        self.diag().into_iter().fold(T::id_add(), |acc, x| acc + x)
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                self.nrows, self.ncols, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let (nrows, ncols) = self.shape();
+        self.get_mut(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                nrows, ncols, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T, U, V> Add<DMatrix<U>> for DMatrix<T>
+where
+    for<'a, 'b> &'a T: Add<&'b U, Output = V>,
+{
+    type Output = DMatrix<V>;
+
+    fn add(self, rhs: DMatrix<U>) -> Self::Output {
+        self.assert_same_shape(&rhs, "add");
+        self.map(|i, j, x| x + &rhs[(i, j)])
+    }
+}
+
+impl<T, U> AddAssign<DMatrix<U>> for DMatrix<T>
+where
+    for<'a> T: AddAssign<&'a U>,
+{
+    fn add_assign(&mut self, rhs: DMatrix<U>) {
+        self.assert_same_shape(&rhs, "add");
+        self.map_mut(|i, j, x| *x += &rhs[(i, j)]);
+    }
+}
+
+impl<T, U, V> Sub<DMatrix<U>> for DMatrix<T>
+where
+    for<'a, 'b> &'a T: Sub<&'b U, Output = V>,
+{
+    type Output = DMatrix<V>;
+
+    fn sub(self, rhs: DMatrix<U>) -> Self::Output {
+        self.assert_same_shape(&rhs, "subtract");
+        self.map(|i, j, x| x - &rhs[(i, j)])
+    }
+}
+
+impl<T, U> SubAssign<DMatrix<U>> for DMatrix<T>
+where
+    for<'a> T: SubAssign<&'a U>,
+{
+    fn sub_assign(&mut self, rhs: DMatrix<U>) {
+        self.assert_same_shape(&rhs, "subtract");
+        self.map_mut(|i, j, x| *x -= &rhs[(i, j)]);
+    }
+}
+
+impl<T, U> Neg for DMatrix<T>
+where
+    for<'a> &'a T: Neg<Output = U>,
+{
+    type Output = DMatrix<U>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|_, _, x| -x)
+    }
+}
+
+impl<T, U, V, W> Mul<DMatrix<U>> for DMatrix<T>
+where
+    for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+    V: Add<V, Output = W>,
+    W: Add<V, Output = W> + IdAdd,
+{
+    type Output = DMatrix<W>;
+
+    fn mul(self, rhs: DMatrix<U>) -> Self::Output {
+        assert!(
+            self.ncols == rhs.nrows,
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+
+        DMatrix::from_fn(self.nrows, rhs.ncols, |i, j| {
+            (0..self.ncols).fold(W::id_add(), |x, k| x + &self[(i, k)] * &rhs[(k, j)])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale() {
+        let mut mat = DMatrix::from_rows(vec![[8, 6, -1], [0, 7, 2]]);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![[40, 30, -5], [0, 35, 10]]),
+            mat.scale(5)
+        );
+
+        mat.scale_mut(5);
+        assert_eq!(DMatrix::from_rows(vec![[40, 30, -5], [0, 35, 10]]), mat);
+    }
+
+    #[test]
+    fn trace() {
+        let mat = DMatrix::from_rows(vec![[2, 5, 7], [6, 1, -1], [3, -2, 4]]);
+        assert_eq!(7, mat.trace());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is (2, 3) but the index is (2, 0)")]
+    fn index_out_of_bounds() {
+        let mat = DMatrix::from_rows(vec![[8, 6, -1], [0, 7, 2]]);
+        let _ = mat[(2, 0)];
+    }
+
+    #[test]
+    fn add() {
+        let mut mat1 = DMatrix::from_rows(vec![[8, 6, -1], [0, 7, 2]]);
+        let mat2 = DMatrix::from_rows(vec![[1, 0, 5], [3, 2, -1]]);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![[9, 6, 4], [3, 9, 1]]),
+            mat1.clone() + mat2.clone()
+        );
+
+        mat1 += mat2;
+        assert_eq!(DMatrix::from_rows(vec![[9, 6, 4], [3, 9, 1]]), mat1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2x3 matrix and a 3x2 matrix")]
+    fn add_mismatch() {
+        let _ = DMatrix::<i32>::id_add(2, 3) + DMatrix::<i32>::id_add(3, 2);
+    }
+
+    #[test]
+    fn sub() {
+        let mut mat1 = DMatrix::from_rows(vec![[8, 6, -1], [0, 7, 2]]);
+        let mat2 = DMatrix::from_rows(vec![[1, 0, 5], [3, 2, -1]]);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![[7, 6, -6], [-3, 5, 3]]),
+            mat1.clone() - mat2.clone()
+        );
+
+        mat1 -= mat2;
+        assert_eq!(DMatrix::from_rows(vec![[7, 6, -6], [-3, 5, 3]]), mat1);
+    }
+
+    #[test]
+    fn neg() {
+        let mat = DMatrix::from_rows(vec![[1, 0, 5], [3, 2, -1]]);
+        assert_eq!(DMatrix::from_rows(vec![[-1, 0, -5], [-3, -2, 1]]), -mat);
+    }
+
+    #[test]
+    fn mul() {
+        let mat1 = DMatrix::from_rows(vec![[8, 6, -1], [0, 7, 2], [4, 4, 5], [-3, -5, 3]]);
+        let mat2 = DMatrix::from_rows(vec![[3, -1], [2, -3], [-2, 1]]);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![[38, -27], [10, -19], [10, -11], [-25, 21]]),
+            mat1 * mat2
+        );
+    }
+
+    #[test]
+    fn matmul() {
+        let mat1 = DMatrix::from_fn(40, 30, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+        let mat2 = DMatrix::from_fn(30, 20, |i, j| ((i * 5 + j) % 7) as f64 - 3.0);
+
+        assert_eq!(mat1.clone() * mat2.clone(), mat1.matmul(&mat2));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply a 2x3 matrix by a 2x3 matrix")]
+    fn mul_mismatch() {
+        let _ = DMatrix::<i32>::id_add(2, 3) * DMatrix::<i32>::id_add(2, 3);
+    }
+}
@@ -0,0 +1,172 @@
+use super::DMatrix;
+use crate::identity::IdAdd;
+use rayon::{iter::Either, prelude::*};
+use std::ops::{Add, Mul, Sub};
+
+impl<T> DMatrix<T> {
+    /// Returns a parallel iterator over the rows.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &[T]>
+    where
+        T: Sync,
+    {
+        let ncols = self.ncols;
+        (0..self.nrows)
+            .into_par_iter()
+            .map(move |i| &self.data[i * ncols..(i + 1) * ncols])
+    }
+
+    /// Returns a parallel iterator over the rows, which may be modified in place.
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]>
+    where
+        T: Send,
+    {
+        // `par_chunks_mut` cannot produce the empty rows of an `n x 0` matrix.
+        if self.ncols == 0 {
+            Either::Left((0..self.nrows).into_par_iter().map(|_| Default::default()))
+        } else {
+            Either::Right(self.data.par_chunks_mut(self.ncols))
+        }
+    }
+
+    /// Parallel counterpart of [`map`](Self::map).
+    pub fn par_map<U, F>(&self, f: F) -> DMatrix<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(usize, usize, &T) -> U + Sync + Send,
+    {
+        let ncols = self.ncols;
+        let data = self
+            .data
+            .par_iter()
+            .enumerate()
+            .map(|(k, x)| f(k / ncols, k % ncols, x))
+            .collect();
+
+        DMatrix::from_vec(self.nrows, ncols, data)
+    }
+
+    /// Parallel counterpart of [`map_mut`](Self::map_mut).
+    pub fn par_map_mut<F>(&mut self, f: F)
+    where
+        T: Send,
+        F: Fn(usize, usize, &mut T) + Sync + Send,
+    {
+        let ncols = self.ncols;
+        self.data
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(k, x)| f(k / ncols, k % ncols, x));
+    }
+
+    /// Returns `self + rhs`, adding entries on separate threads.
+    /// Panics if the shapes differ.
+    pub fn par_add<U, V>(&self, rhs: &DMatrix<U>) -> DMatrix<V>
+    where
+        for<'a, 'b> &'a T: Add<&'b U, Output = V>,
+        T: Sync,
+        U: Sync,
+        V: Send,
+    {
+        self.assert_same_shape(rhs, "add");
+        self.par_map(|i, j, x| x + &rhs[(i, j)])
+    }
+
+    /// Returns `self - rhs`, subtracting entries on separate threads.
+    /// Panics if the shapes differ.
+    pub fn par_sub<U, V>(&self, rhs: &DMatrix<U>) -> DMatrix<V>
+    where
+        for<'a, 'b> &'a T: Sub<&'b U, Output = V>,
+        T: Sync,
+        U: Sync,
+        V: Send,
+    {
+        self.assert_same_shape(rhs, "subtract");
+        self.par_map(|i, j, x| x - &rhs[(i, j)])
+    }
+
+    /// Parallel counterpart of `*`, computing entries of the product on separate threads.
+    pub fn par_mul<U, V, W>(&self, rhs: &DMatrix<U>) -> DMatrix<W>
+    where
+        for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+        V: Add<V, Output = W>,
+        W: Add<V, Output = W> + IdAdd + Send,
+        T: Sync,
+        U: Sync,
+    {
+        assert!(
+            self.ncols == rhs.nrows,
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+
+        let n = rhs.ncols;
+        let data = (0..self.nrows * n)
+            .into_par_iter()
+            .map(|k| {
+                let (i, j) = (k / n, k % n);
+                (0..self.ncols).fold(W::id_add(), |x, l| x + &self[(i, l)] * &rhs[(l, j)])
+            })
+            .collect();
+
+        DMatrix::from_vec(self.nrows, n, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::sample_dyn;
+
+    #[test]
+    fn par_iter() {
+        let mat = DMatrix::from_rows(vec![[1, 2, 3], [4, 5, 6]]);
+        let sums: Vec<i32> = mat.par_iter().map(|row| row.iter().sum()).collect();
+
+        assert_eq!(vec![6, 15], sums);
+
+        let mut mat = DMatrix::<i32>::from_vec(3, 0, vec![]);
+        assert_eq!(3, mat.par_iter().count());
+        assert_eq!(3, mat.par_iter_mut().count());
+    }
+
+    #[test]
+    fn par_map() {
+        let mut mat = sample_dyn::<i64>(70, 30, 0);
+        let expected = mat.map(|i, j, &x| x * (i + j) as i64);
+
+        assert_eq!(expected, mat.par_map(|i, j, &x| x * (i + j) as i64));
+
+        mat.par_map_mut(|i, j, x| *x *= (i + j) as i64);
+        assert_eq!(expected, mat);
+    }
+
+    #[test]
+    fn par_add_sub() {
+        let a = sample_dyn::<i64>(65, 9, 0);
+        let b = a.map(|i, j, &x| x * 2 - (i * j) as i64);
+
+        assert_eq!(a.clone() + b.clone(), a.par_add(&b));
+        assert_eq!(a.clone() - b.clone(), a.par_sub(&b));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2x3 matrix and a 3x2 matrix")]
+    fn par_add_shape_mismatch() {
+        let _ = sample_dyn::<i64>(2, 3, 0).par_add(&sample_dyn::<i64>(3, 2, 0));
+    }
+
+    #[test]
+    fn par_mul() {
+        let a = sample_dyn::<i64>(40, 30, 1);
+        let b = sample_dyn::<i64>(30, 20, 2);
+        assert_eq!(a.clone() * b.clone(), a.par_mul(&b));
+
+        let a = a.map(|_, _, &x| x as f64);
+        let b = b.map(|_, _, &x| x as f64);
+        assert_eq!(a.clone() * b.clone(), a.par_mul(&b));
+    }
+}
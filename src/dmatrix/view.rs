@@ -0,0 +1,663 @@
+use super::DMatrix;
+use crate::{
+    identity::IdAdd,
+    matrix::view::{window, MatrixView, MatrixViewMut},
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign},
+};
+
+/// Borrowed block of a matrix whose shape is only known at runtime. Element
+/// `(i, j)` of the view lies at `i * row_stride + j * col_stride` in the
+/// borrowed storage.
+pub struct DMatrixView<'a, T> {
+    data: &'a [T],
+    nrows: usize,
+    ncols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Mutable counterpart of [`DMatrixView`], writing straight into the parent.
+pub struct DMatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    nrows: usize,
+    ncols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<T> DMatrix<T> {
+    /// Borrows the `R x C` block whose top-left corner is `(i0, j0)`.
+    pub fn view<const R: usize, const C: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'_, T, R, C> {
+        let range = window(self.shape(), (self.ncols, 1), (i0, j0), (R, C));
+        MatrixView::from_raw(&self.data[range], self.ncols, 1)
+    }
+
+    pub fn view_mut<const R: usize, const C: usize>(
+        &mut self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixViewMut<'_, T, R, C> {
+        let range = window(self.shape(), (self.ncols, 1), (i0, j0), (R, C));
+        MatrixViewMut::from_raw(&mut self.data[range], self.ncols, 1)
+    }
+
+    /// Borrows the `nrows x ncols` block whose top-left corner is `(i0, j0)`.
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> DMatrixView<'_, T> {
+        self.as_view().view_dyn(i0, j0, nrows, ncols)
+    }
+
+    pub fn view_dyn_mut(
+        &mut self,
+        i0: usize,
+        j0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> DMatrixViewMut<'_, T> {
+        self.as_view_mut().into_view_dyn_mut(i0, j0, nrows, ncols)
+    }
+
+    pub fn as_view(&self) -> DMatrixView<'_, T> {
+        DMatrixView {
+            data: &self.data,
+            nrows: self.nrows,
+            ncols: self.ncols,
+            row_stride: self.ncols,
+            col_stride: 1,
+        }
+    }
+
+    pub fn as_view_mut(&mut self) -> DMatrixViewMut<'_, T> {
+        DMatrixViewMut {
+            data: &mut self.data,
+            nrows: self.nrows,
+            ncols: self.ncols,
+            row_stride: self.ncols,
+            col_stride: 1,
+        }
+    }
+}
+
+impl<'a, T> DMatrixView<'a, T> {
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        (i < self.nrows && j < self.ncols)
+            .then(|| &self.data[i * self.row_stride + j * self.col_stride])
+    }
+
+    /// Iterates over the elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..view.nrows).flat_map(move |i| {
+            (0..view.ncols).map(move |j| &view.data[i * view.row_stride + j * view.col_stride])
+        })
+    }
+
+    pub fn view<const R: usize, const C: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'a, T, R, C> {
+        let range = window(
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            (i0, j0),
+            (R, C),
+        );
+        MatrixView::from_raw(&self.data[range], self.row_stride, self.col_stride)
+    }
+
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> Self {
+        let range = window(
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            (i0, j0),
+            (nrows, ncols),
+        );
+
+        Self {
+            data: &self.data[range],
+            nrows,
+            ncols,
+            ..*self
+        }
+    }
+
+    /// Swaps the strides, so no elements are moved.
+    pub fn transpose(self) -> Self {
+        Self {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> DMatrix<U>
+    where
+        F: FnMut(usize, usize, &T) -> U,
+    {
+        DMatrix::from_fn(self.nrows, self.ncols, |i, j| f(i, j, &self[(i, j)]))
+    }
+
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Clone,
+    {
+        self.map(|_, _, x| x.clone())
+    }
+
+    fn assert_same_shape<U>(&self, rhs: &DMatrixView<'_, U>, op: &str) {
+        assert!(
+            self.shape() == rhs.shape(),
+            "cannot {} a {}x{} matrix and a {}x{} matrix",
+            op,
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+    }
+}
+
+impl<'a, T> DMatrixViewMut<'a, T> {
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn as_view(&self) -> DMatrixView<'_, T> {
+        DMatrixView {
+            data: self.data,
+            nrows: self.nrows,
+            ncols: self.ncols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    pub fn as_view_mut(&mut self) -> DMatrixViewMut<'_, T> {
+        DMatrixViewMut {
+            data: self.data,
+            ..*self
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.as_view().get(i, j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        (i < self.nrows && j < self.ncols)
+            .then(|| &mut self.data[i * self.row_stride + j * self.col_stride])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_view().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (ncols, row_stride, col_stride) = (self.ncols, self.row_stride, self.col_stride);
+        let ptr = self.data.as_mut_ptr();
+
+        // SAFETY: unlike the fixed-size view, `nrows` and `ncols` are runtime
+        // fields, but they are private and only ever set alongside `data`:
+        // `DMatrix::as_view_mut` covers a whole matrix with its own shape,
+        // the view's `as_view_mut` reborrows it unchanged,
+        // `into_view_dyn_mut` shrinks both through `window()`, the conversion
+        // from `MatrixViewMut` copies `R` and `C` from a view that passed
+        // `window()`, and `transpose` swaps the shape with the strides. So
+        // `(nrows - 1) * row_stride + (ncols - 1) * col_stride` is the last
+        // index of `data`, and an empty view yields nothing. One stride is 1
+        // and the other spans a full row of the underlying storage, so
+        // distinct positions get distinct offsets and no references alias.
+        (0..self.nrows).flat_map(move |i| {
+            (0..ncols).map(move |j| unsafe { &mut *ptr.add(i * row_stride + j * col_stride) })
+        })
+    }
+
+    pub fn view<const R: usize, const C: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'_, T, R, C> {
+        self.as_view().view(i0, j0)
+    }
+
+    pub fn view_mut<const R: usize, const C: usize>(
+        &mut self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixViewMut<'_, T, R, C> {
+        let range = window(
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            (i0, j0),
+            (R, C),
+        );
+        MatrixViewMut::from_raw(&mut self.data[range], self.row_stride, self.col_stride)
+    }
+
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> DMatrixView<'_, T> {
+        self.as_view().view_dyn(i0, j0, nrows, ncols)
+    }
+
+    pub fn view_dyn_mut(
+        &mut self,
+        i0: usize,
+        j0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> DMatrixViewMut<'_, T> {
+        self.as_view_mut().into_view_dyn_mut(i0, j0, nrows, ncols)
+    }
+
+    pub(crate) fn into_view_dyn_mut(
+        self,
+        i0: usize,
+        j0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        let range = window(
+            self.shape(),
+            (self.row_stride, self.col_stride),
+            (i0, j0),
+            (nrows, ncols),
+        );
+
+        Self {
+            data: &mut self.data[range],
+            nrows,
+            ncols,
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            nrows: self.ncols,
+            ncols: self.nrows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    pub fn map_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                f(i, j, &mut self[(i, j)]);
+            }
+        }
+    }
+
+    /// Copies `src` into the parent.
+    pub fn assign(&mut self, src: DMatrixView<'_, T>)
+    where
+        T: Clone,
+    {
+        assert!(
+            self.shape() == src.shape(),
+            "cannot assign a {}x{} matrix to a {}x{} view",
+            src.nrows,
+            src.ncols,
+            self.nrows,
+            self.ncols
+        );
+
+        self.map_mut(|i, j, x| x.clone_from(&src[(i, j)]));
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.map_mut(|_, _, x| *x = value.clone());
+    }
+
+    pub fn to_dmatrix(&self) -> DMatrix<T>
+    where
+        T: Clone,
+    {
+        self.as_view().to_dmatrix()
+    }
+}
+
+impl<T> Clone for DMatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DMatrixView<'_, T> {}
+
+impl<'a, T, const R: usize, const C: usize> From<MatrixView<'a, T, R, C>> for DMatrixView<'a, T> {
+    fn from(value: MatrixView<'a, T, R, C>) -> Self {
+        let (data, row_stride, col_stride) = value.into_raw();
+        Self {
+            data,
+            nrows: R,
+            ncols: C,
+            row_stride,
+            col_stride,
+        }
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> From<MatrixViewMut<'a, T, R, C>>
+    for DMatrixViewMut<'a, T>
+{
+    fn from(value: MatrixViewMut<'a, T, R, C>) -> Self {
+        let (data, row_stride, col_stride) = value.into_raw();
+        Self {
+            data,
+            nrows: R,
+            ncols: C,
+            row_stride,
+            col_stride,
+        }
+    }
+}
+
+impl<'a, T> From<DMatrixViewMut<'a, T>> for DMatrixView<'a, T> {
+    fn from(value: DMatrixViewMut<'a, T>) -> Self {
+        Self {
+            data: value.data,
+            nrows: value.nrows,
+            ncols: value.ncols,
+            row_stride: value.row_stride,
+            col_stride: value.col_stride,
+        }
+    }
+}
+
+impl<T> Debug for DMatrixView<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                (0..self.nrows).map(|i| (0..self.ncols).map(|j| &self[(i, j)]).collect::<Vec<_>>()),
+            )
+            .finish()
+    }
+}
+
+impl<T> Debug for DMatrixViewMut<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+impl<T, U> PartialEq<DMatrixView<'_, U>> for DMatrixView<'_, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DMatrixView<'_, U>) -> bool {
+        self.shape() == other.shape() && self.iter().zip(other.iter()).all(|(x, y)| x == y)
+    }
+}
+
+impl<T, U> PartialEq<DMatrix<U>> for DMatrixView<'_, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DMatrix<U>) -> bool {
+        *self == other.as_view()
+    }
+}
+
+impl<T, U> PartialEq<DMatrixView<'_, U>> for DMatrix<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &DMatrixView<'_, U>) -> bool {
+        self.as_view() == *other
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                self.nrows, self.ncols, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                self.nrows, self.ncols, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrixViewMut<'_, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let (nrows, ncols) = self.shape();
+        self.get_mut(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                nrows, ncols, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T, U, V> Add<DMatrixView<'_, U>> for DMatrixView<'_, T>
+where
+    for<'a, 'b> &'a T: Add<&'b U, Output = V>,
+{
+    type Output = DMatrix<V>;
+
+    fn add(self, rhs: DMatrixView<'_, U>) -> Self::Output {
+        self.assert_same_shape(&rhs, "add");
+        self.map(|i, j, x| x + &rhs[(i, j)])
+    }
+}
+
+impl<T, U, V> Sub<DMatrixView<'_, U>> for DMatrixView<'_, T>
+where
+    for<'a, 'b> &'a T: Sub<&'b U, Output = V>,
+{
+    type Output = DMatrix<V>;
+
+    fn sub(self, rhs: DMatrixView<'_, U>) -> Self::Output {
+        self.assert_same_shape(&rhs, "subtract");
+        self.map(|i, j, x| x - &rhs[(i, j)])
+    }
+}
+
+impl<T, U, V, W> Mul<DMatrixView<'_, U>> for DMatrixView<'_, T>
+where
+    for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+    V: Add<V, Output = W>,
+    W: Add<V, Output = W> + IdAdd,
+{
+    type Output = DMatrix<W>;
+
+    fn mul(self, rhs: DMatrixView<'_, U>) -> Self::Output {
+        assert!(
+            self.ncols == rhs.nrows,
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.nrows,
+            self.ncols,
+            rhs.nrows,
+            rhs.ncols
+        );
+
+        DMatrix::from_fn(self.nrows, rhs.ncols, |i, j| {
+            (0..self.ncols).fold(W::id_add(), |x, k| x + &self[(i, k)] * &rhs[(k, j)])
+        })
+    }
+}
+
+impl<T, U> AddAssign<DMatrixView<'_, U>> for DMatrixViewMut<'_, T>
+where
+    for<'a> T: AddAssign<&'a U>,
+{
+    fn add_assign(&mut self, rhs: DMatrixView<'_, U>) {
+        self.as_view().assert_same_shape(&rhs, "add");
+        self.map_mut(|i, j, x| *x += &rhs[(i, j)]);
+    }
+}
+
+impl<T, U> SubAssign<DMatrixView<'_, U>> for DMatrixViewMut<'_, T>
+where
+    for<'a> T: SubAssign<&'a U>,
+{
+    fn sub_assign(&mut self, rhs: DMatrixView<'_, U>) {
+        self.as_view().assert_same_shape(&rhs, "subtract");
+        self.map_mut(|i, j, x| *x -= &rhs[(i, j)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix::Matrix, testing::sample_dyn};
+
+    #[test]
+    fn view_dyn() {
+        let mat = sample_dyn::<i32>(4, 5, 0);
+        let view = mat.view_dyn(1, 2, 2, 3);
+
+        assert_eq!((2, 3), view.shape());
+        assert_eq!(DMatrix::from_rows(vec![[8, 2, -4], [-7, 10, 4]]), view);
+        assert_eq!(None, view.get(0, 3));
+        assert_eq!(
+            DMatrix::from_rows(vec![[2, 10], [-4, 4]]),
+            view.view_dyn(0, 1, 2, 2).transpose()
+        );
+        assert_eq!(Matrix::from_rows([[10, 4]]), view.view::<1, 2>(1, 1));
+    }
+
+    #[test]
+    fn view_dyn_of_matrix() {
+        let mut mat = Matrix::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(
+            DMatrix::from_rows(vec![[5, 6], [8, 9]]),
+            mat.view_dyn(1, 1, 2, 2)
+        );
+
+        mat.view_dyn_mut(0, 1, 3, 1).fill(0);
+        assert_eq!(Matrix::from_rows([[1, 0, 3], [4, 0, 6], [7, 0, 9]]), mat);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take a 3x1 view at (2, 4) of a 4x5 matrix")]
+    fn view_dyn_out_of_bounds() {
+        let _ = sample_dyn::<i32>(4, 5, 0).view_dyn(2, 4, 3, 1);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mat = sample_dyn::<i32>(4, 5, 0);
+        let a = mat.view_dyn(0, 0, 2, 2);
+        let b = mat.view_dyn(2, 3, 2, 2);
+
+        assert_eq!(DMatrix::from_rows(vec![[-1, 10], [-8, -20]]), a + b);
+        assert_eq!(DMatrix::from_rows(vec![[-21, 2], [2, 2]]), a - b);
+        assert_eq!(DMatrix::from_rows(vec![[-140, -110], [15, 87]]), a * b);
+        assert_eq!(
+            mat.view_dyn(0, 0, 2, 4).to_dmatrix() * mat.view_dyn(0, 1, 4, 3).to_dmatrix(),
+            mat.view_dyn(0, 0, 2, 4) * mat.view_dyn(0, 1, 4, 3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a 2x2 matrix and a 2x3 matrix")]
+    fn add_shape_mismatch() {
+        let mat = sample_dyn::<i32>(4, 5, 0);
+        let _ = mat.view_dyn(0, 0, 2, 2) + mat.view_dyn(0, 0, 2, 3);
+    }
+
+    #[test]
+    fn assign() {
+        let mut mat = sample_dyn::<i32>(4, 5, 0);
+        let block = DMatrix::from_rows(vec![[0, -1], [-2, -3]]);
+
+        let mut view = mat.view_dyn_mut(1, 3, 2, 2);
+        view.assign(block.as_view());
+        view -= block.as_view();
+        view.view_mut::<1, 1>(1, 1)[(0, 0)] = 7;
+        view.iter_mut().for_each(|x| *x += 1);
+
+        assert_eq!(
+            DMatrix::from_rows(vec![
+                [-11, 6, 0, -6, 11],
+                [-3, -9, 8, 1, 1],
+                [5, -1, -7, 1, 8],
+                [-10, 7, 1, -5, -11],
+            ]),
+            mat
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot assign a 2x2 matrix to a 1x2 view")]
+    fn assign_shape_mismatch() {
+        let mut mat = sample_dyn::<i32>(4, 5, 0);
+        let block = DMatrix::from_rows(vec![[0, -1], [-2, -3]]);
+        mat.view_dyn_mut(0, 0, 1, 2).assign(block.as_view());
+    }
+}
@@ -1,7 +1,15 @@
 pub mod prelude;
 
+pub mod complex;
+pub mod dmatrix;
 pub mod identity;
 pub mod matrix;
+mod par;
 pub mod recip;
+pub mod scalar;
+pub mod solvers;
+pub mod sparse;
+#[cfg(test)]
+mod testing;
 pub mod vec2;
 pub mod vec3;
@@ -10,9 +10,27 @@ use std::{
 };
 
 pub mod aliases;
+pub mod cholesky;
 mod cmp;
+mod functions;
+pub(crate) mod gemm;
 pub mod iter;
+pub mod ldl;
+pub mod lu;
+mod norm;
 mod ops;
+#[cfg(feature = "rayon")]
+mod par;
+pub mod qr;
+mod rref;
+pub mod schur;
+pub mod solve;
+mod stationary;
+pub mod strassen;
+pub mod subspace;
+pub mod svd;
+pub mod symmetric_eigen;
+pub mod view;
 
 #[derive(Debug, Clone)]
 pub struct Matrix<T, const M: usize, const N: usize>([[T; N]; M]);
@@ -81,6 +99,14 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
         (j < N).then(|| array::from_fn::<_, M, _>(|i| unsafe { ptr::read(&self[(i, j)]) }))
     }
 
+    pub fn transpose(self) -> Matrix<T, N, M> {
+        Matrix::from_cols(self.take_rows())
+    }
+
+    pub fn transpose_ref(&self) -> Matrix<&T, N, M> {
+        Matrix::from_cols(self.rows())
+    }
+
     pub fn get(&self, i: usize, j: usize) -> Option<&T> {
         self.0.get(i).and_then(|row| row.get(j))
     }
@@ -220,6 +246,17 @@ mod tests {
         assert_eq!(Matrix::from_rows([[1, 2, 3], [4, 5, 6]]), mat);
     }
 
+    #[test]
+    fn transpose() {
+        let mat = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(
+            Matrix::from_rows([[&1, &4], [&2, &5], [&3, &6]]),
+            mat.transpose_ref()
+        );
+        assert_eq!(Matrix::from_rows([[1, 4], [2, 5], [3, 6]]), mat.transpose());
+    }
+
     #[test]
     fn rows_mut() {
         let mut mat = Matrix::from_rows([[5, -1, 2], [-5, 0, -1]]);
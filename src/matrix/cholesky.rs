@@ -0,0 +1,175 @@
+use super::Matrix;
+use crate::{
+    identity::{IdAdd, IdMul},
+    scalar::Real,
+};
+
+/// Cholesky decomposition of a symmetric positive-definite matrix, `A = L * Lᵀ`.
+#[derive(Debug, Clone)]
+pub struct Cholesky<T, const M: usize> {
+    l: Matrix<T, M, M>,
+}
+
+impl<T, const M: usize> Cholesky<T, M>
+where
+    T: Real,
+{
+    pub fn new(a: Matrix<T, M, M>) -> Option<Self> {
+        Self::factor(a, |rest, lj, j, a| {
+            for (r, li) in rest.iter_mut().enumerate() {
+                li[j] = entry(a, li, lj, j + 1 + r, j);
+            }
+        })
+    }
+
+    /// Runs the factorization, handing the rows below the diagonal of each
+    /// column to `rows`.
+    pub(crate) fn factor<F>(a: Matrix<T, M, M>, rows: F) -> Option<Self>
+    where
+        F: Fn(&mut [[T; M]], &[T; M], usize, &Matrix<T, M, M>),
+    {
+        if !a.is_symmetric() {
+            return None;
+        }
+
+        let tol = T::EPSILON * T::from_usize(M) * a.max_abs();
+        let mut l = Matrix::<T, M, M>::id_add();
+
+        for j in 0..M {
+            let d = (0..j).fold(a[(j, j)], |d, k| d - l[(j, k)] * l[(j, k)]);
+            if d <= tol {
+                return None;
+            }
+            l[(j, j)] = d.sqrt();
+
+            let (top, rest) = l.0.split_at_mut(j + 1);
+            rows(rest, &top[j], j, &a);
+        }
+
+        Some(Self { l })
+    }
+
+    pub fn l(&self) -> &Matrix<T, M, M> {
+        &self.l
+    }
+
+    pub fn det(&self) -> T {
+        let det = (0..M).fold(T::id_mul(), |det, k| det * self.l[(k, k)]);
+        det * det
+    }
+
+    pub fn solve<const K: usize>(&self, b: &Matrix<T, M, K>) -> Matrix<T, M, K> {
+        let mut x = b.clone();
+        for c in 0..K {
+            for i in 0..M {
+                for k in 0..i {
+                    x[(i, c)] = x[(i, c)] - self.l[(i, k)] * x[(k, c)];
+                }
+                x[(i, c)] = x[(i, c)] / self.l[(i, i)];
+            }
+
+            for i in (0..M).rev() {
+                for k in i + 1..M {
+                    x[(i, c)] = x[(i, c)] - self.l[(k, i)] * x[(k, c)];
+                }
+                x[(i, c)] = x[(i, c)] / self.l[(i, i)];
+            }
+        }
+
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, M, M> {
+        self.solve(&Matrix::id_mul())
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn cholesky(self) -> Option<Cholesky<T, M>> {
+        Cholesky::new(self)
+    }
+
+    pub(crate) fn is_symmetric(&self) -> bool {
+        let tol = T::EPSILON * T::from_usize(M) * self.max_abs();
+        (0..M).all(|i| (0..i).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
+}
+
+/// Returns `L[i][j]` from row `i` of `L`, already filled left of column `j`,
+/// and the finished row `j`.
+pub(crate) fn entry<T, const M: usize>(
+    a: &Matrix<T, M, M>,
+    li: &[T; M],
+    lj: &[T; M],
+    i: usize,
+    j: usize,
+) -> T
+where
+    T: Real,
+{
+    let s = (0..j).fold(a[(i, j)], |s, k| s - li[k] * lj[k]);
+    s / lj[j]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn l() {
+        let mat = Matrix::from_rows([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        assert_approx(
+            Matrix::from_rows([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]),
+            mat.cholesky().unwrap().l().clone(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn det() {
+        let mat = Matrix::from_rows([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        assert!((36.0_f64 - mat.cholesky().unwrap().det()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solve() {
+        let mat = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        let chol = mat.clone().cholesky().unwrap();
+
+        assert_approx(
+            Matrix::from_rows([[1.0], [2.0], [3.0]]),
+            chol.solve(&Matrix::from_rows([[0.0], [0.0], [4.0]])),
+            1e-12,
+        );
+        assert_approx(
+            Matrix::from_rows([[0.75, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.75]]),
+            chol.inverse(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn not_spd() {
+        let indefinite = Matrix::from_rows([[1.0, 2.0], [2.0, 1.0]]);
+        let asymmetric = Matrix::from_rows([[2.0, 1.0], [0.0, 2.0]]);
+        let semidefinite = Matrix::from_rows([[1.0, 1.0], [1.0, 1.0]]);
+
+        assert!(indefinite.cholesky().is_none());
+        assert!(asymmetric.cholesky().is_none());
+        assert!(semidefinite.cholesky().is_none());
+    }
+}
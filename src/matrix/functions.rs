@@ -0,0 +1,253 @@
+use super::{lu::Lu, Matrix};
+use crate::{identity::IdMul, scalar::Real};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Numerator coefficients of the degree 13 Padé approximant to `exp`.
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Largest 1-norm for which the degree 13 Padé approximant is accurate to
+/// double precision.
+const THETA_13: f64 = 5.371920351148152;
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    /// Returns the matrix exponential by scaling and squaring a degree 13
+    /// Padé approximant.
+    pub fn expm(&self) -> Self {
+        let norm = self.norm_1();
+        assert!(
+            norm.is_finite(),
+            "cannot exponentiate a matrix with non-finite entries"
+        );
+
+        // Halve `A` until its norm drops to `THETA_13`; powers of two scale exactly.
+        let squarings = (norm.to_f64() / THETA_13).log2().ceil().max(0.0) as i32;
+        let scale = T::from_f64(2f64.powi(-squarings));
+        let a = self.map(|_, _, &x| x * scale);
+
+        let b = PADE_13.map(T::from_f64);
+        let id = Self::id_mul();
+        let a2 = product(&a, &a);
+        let a4 = product(&a2, &a2);
+        let a6 = product(&a4, &a2);
+
+        let one = T::id_mul();
+        let u_high = product(&a6, &combine(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]));
+        let u = product(
+            &a,
+            &combine(&[
+                (one, &u_high),
+                (b[7], &a6),
+                (b[5], &a4),
+                (b[3], &a2),
+                (b[1], &id),
+            ]),
+        );
+        let v_high = product(&a6, &combine(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]));
+        let v = combine(&[
+            (one, &v_high),
+            (b[6], &a6),
+            (b[4], &a4),
+            (b[2], &a2),
+            (b[0], &id),
+        ]);
+
+        // `V - U` is well conditioned once the norm of `A` is below `THETA_13`.
+        let mut exp = Lu::new(combine(&[(one, &v), (-one, &u)]))
+            .solve(&combine(&[(one, &v), (one, &u)]))
+            .expect("cannot exponentiate a matrix whose Padé denominator is singular");
+        for _ in 0..squarings {
+            exp = product(&exp, &exp);
+        }
+
+        exp
+    }
+
+    /// Returns the principal square root by Denman–Beavers iteration, or
+    /// `None` if the matrix has no principal square root, such as when it has
+    /// an eigenvalue on the closed negative real axis.
+    pub fn sqrtm(&self) -> Option<Self> {
+        let half = T::id_mul() / (T::id_mul() + T::id_mul());
+        let tol = T::EPSILON * T::from_usize(M) * self.norm_fro();
+
+        let mut y = self.clone();
+        let mut z = Self::id_mul();
+        for _ in 0..MAX_ITERATIONS {
+            let y_inv = y.try_recip()?;
+            let z_inv = z.try_recip()?;
+            let next = combine(&[(half, &y), (half, &z_inv)]);
+            z = combine(&[(half, &z), (half, &y_inv)]);
+
+            let delta = combine(&[(T::id_mul(), &next), (-T::id_mul(), &y)]).norm_fro();
+            y = next;
+            if delta <= tol {
+                return Some(y);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the principal logarithm by inverse scaling and squaring, or
+    /// `None` if the matrix has no principal logarithm, such as when it has an
+    /// eigenvalue on the closed negative real axis.
+    pub fn logm(&self) -> Option<Self> {
+        let quarter = T::from_f64(0.25);
+        let id = Self::id_mul();
+
+        let mut a = self.clone();
+        let mut roots = 0;
+        while combine(&[(T::id_mul(), &a), (-T::id_mul(), &id)]).norm_1() > quarter {
+            if roots == MAX_ITERATIONS {
+                return None;
+            }
+            a = a.sqrtm()?;
+            roots += 1;
+        }
+
+        // log(A) = 2 atanh(Z) with Z = (A + I)⁻¹(A - I), whose series
+        // converges quickly now that A is close to the identity.
+        let z = Lu::new(combine(&[(T::id_mul(), &a), (T::id_mul(), &id)]))
+            .solve(&combine(&[(T::id_mul(), &a), (-T::id_mul(), &id)]))
+            .ok()?;
+        let z2 = product(&z, &z);
+
+        let two = T::id_mul() + T::id_mul();
+        let tol = T::EPSILON * z.norm_1();
+        let mut power = z.clone();
+        let mut log = z.clone();
+        for k in 1..MAX_ITERATIONS {
+            power = product(&power, &z2);
+            let coeff = T::id_mul() / T::from_usize(2 * k + 1);
+            log = combine(&[(T::id_mul(), &log), (coeff, &power)]);
+            if coeff * power.norm_1() <= tol {
+                break;
+            }
+        }
+
+        let mut scale = two;
+        for _ in 0..roots {
+            scale = scale * two;
+        }
+        log.map_mut(|_, _, x| *x = *x * scale);
+
+        Some(log)
+    }
+}
+
+fn product<T, const M: usize>(a: &Matrix<T, M, M>, b: &Matrix<T, M, M>) -> Matrix<T, M, M>
+where
+    T: Real,
+{
+    a.map(|i, j, _| (0..M).fold(T::id_add(), |sum, k| sum + a[(i, k)] * b[(k, j)]))
+}
+
+/// Returns the linear combination of `terms`.
+fn combine<T, const M: usize>(terms: &[(T, &Matrix<T, M, M>)]) -> Matrix<T, M, M>
+where
+    T: Real,
+{
+    terms[0].1.map(|i, j, _| {
+        terms
+            .iter()
+            .fold(T::id_add(), |sum, (c, a)| sum + *c * a[(i, j)])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::IdAdd;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn expm() {
+        assert_approx(
+            Matrix::id_mul(),
+            Matrix::<f64, 3, 3>::id_add().expm(),
+            1e-10,
+        );
+        assert_approx(
+            Matrix::from_rows([[1.0, 1.0], [0.0, 1.0]]),
+            Matrix::from_rows([[0.0, 1.0], [0.0, 0.0]]).expm(),
+            1e-10,
+        );
+
+        let t = 2.5_f64;
+        assert_approx(
+            Matrix::from_rows([[t.cos(), -t.sin()], [t.sin(), t.cos()]]),
+            Matrix::from_rows([[0.0, -t], [t, 0.0]]).expm(),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn expm_scaled() {
+        // A norm of 30 forces several squarings.
+        assert_approx(
+            Matrix::from_rows([[10.0_f64.exp(), 0.0], [0.0, (-20.0_f64).exp()]]),
+            Matrix::from_rows([[10.0, 0.0], [0.0, -20.0]]).expm(),
+            1e-10,
+        );
+
+        let a = Matrix::from_rows([[1.0, 2.0, 0.0], [0.0, 1.0, 3.0], [-4.0, 0.0, 2.0]]);
+        let half = a.map(|_, _, &x| x / 2.0).expm();
+        assert_approx(a.expm(), half.clone() * half, 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot exponentiate a matrix with non-finite entries")]
+    fn expm_infinite() {
+        let _ = Matrix::from_rows([[f64::INFINITY, 0.0], [0.0, 1.0]]).expm();
+    }
+
+    #[test]
+    fn sqrtm() {
+        assert_approx(
+            Matrix::from_rows([[2.0, 0.0], [0.0, 3.0]]),
+            Matrix::from_rows([[4.0, 0.0], [0.0, 9.0]]).sqrtm().unwrap(),
+            1e-10,
+        );
+
+        let a = Matrix::from_rows([[4.0, 1.0, 0.0], [1.0, 5.0, 2.0], [0.0, -1.0, 6.0]]);
+        let root = a.sqrtm().unwrap();
+        assert_approx(a, root.clone() * root, 1e-10);
+
+        assert!(Matrix::from_rows([[-1.0, 0.0], [0.0, 1.0]])
+            .sqrtm()
+            .is_none());
+    }
+
+    #[test]
+    fn logm() {
+        assert_approx(
+            Matrix::id_add(),
+            Matrix::<f64, 3, 3>::id_mul().logm().unwrap(),
+            1e-10,
+        );
+
+        let a = Matrix::from_rows([[0.5, 1.0, 0.0], [-1.0, 0.2, 0.3], [0.0, 0.4, -0.1]]);
+        assert_approx(a.clone(), a.expm().logm().unwrap(), 1e-10);
+
+        let b = Matrix::from_rows([[5.0, 1.0], [2.0, 7.0]]);
+        assert_approx(b.clone(), b.logm().unwrap().expm(), 1e-10);
+    }
+}
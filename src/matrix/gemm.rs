@@ -0,0 +1,412 @@
+use super::Matrix;
+use crate::{dmatrix::DMatrix, par};
+use std::{
+    any::{Any, TypeId},
+    ops::{Add, Mul},
+};
+
+/// Products with fewer multiply-adds than this stay on the generic path,
+/// where blocking costs more than it saves.
+const MIN_WORK: usize = 16 * 16 * 16;
+
+/// Rows of `A` processed per block, sized to keep them in L2 cache.
+const MC: usize = 64;
+
+/// Depth of the shared dimension processed per block, sized to keep the
+/// matching rows of `B` in L2 cache.
+const KC: usize = 256;
+
+/// Rows of `C` accumulated in registers at once.
+const MR: usize = 4;
+
+/// Floating-point types with an accelerated multiplication kernel.
+pub(crate) trait Element:
+    Copy + Default + Add<Output = Self> + Mul<Output = Self> + Send + Sync + 'static
+{
+    /// Computes `C[rows, :] += A[rows, depth] * B[depth, :]` for the row-major
+    /// `A` with `p` columns and `B` and `C` with `n` columns.
+    fn block(
+        rows: (usize, usize),
+        depth: (usize, usize),
+        p: usize,
+        n: usize,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    );
+}
+
+/// Multiplies through the accelerated kernel when `T`, `U` and `W` are all
+/// `f32` or all `f64` and the product is large enough to benefit.
+pub(crate) fn try_mul<T, U, W, const M: usize, const P: usize, const N: usize>(
+    a: &Matrix<T, M, P>,
+    b: &Matrix<U, P, N>,
+) -> Option<Matrix<W, M, N>>
+where
+    T: 'static,
+    U: 'static,
+    W: 'static,
+{
+    if M * P * N < MIN_WORK {
+        return None;
+    }
+
+    let mut out = None;
+    let _ = typed::<f64, _, _, _, M, P, N>(a, b, &mut out)
+        || typed::<f32, _, _, _, M, P, N>(a, b, &mut out);
+    out
+}
+
+/// Multiplies through the accelerated kernel when `T`, `U` and `W` are all
+/// `f32` or all `f64` and the product is large enough to benefit.
+pub(crate) fn try_mul_dmatrix<T, U, W>(a: &DMatrix<T>, b: &DMatrix<U>) -> Option<DMatrix<W>>
+where
+    T: 'static,
+    U: 'static,
+    W: 'static,
+{
+    let (m, p, n) = (a.nrows(), a.ncols(), b.ncols());
+    if m * p * n < MIN_WORK {
+        return None;
+    }
+
+    fn typed<E, T, U, W>(a: &DMatrix<T>, b: &DMatrix<U>, out: &mut Option<DMatrix<W>>) -> bool
+    where
+        E: Element,
+        T: 'static,
+        U: 'static,
+        W: 'static,
+    {
+        let a = (a as &dyn Any).downcast_ref::<DMatrix<E>>();
+        let b = (b as &dyn Any).downcast_ref::<DMatrix<E>>();
+        let out = (out as &mut dyn Any).downcast_mut::<Option<DMatrix<E>>>();
+
+        match (a, b, out) {
+            (Some(a), Some(b), Some(out)) => {
+                let mut c = vec![E::default(); a.nrows() * b.ncols()];
+                gemm(
+                    a.nrows(),
+                    a.ncols(),
+                    b.ncols(),
+                    a.as_slice(),
+                    b.as_slice(),
+                    &mut c,
+                );
+                *out = Some(DMatrix::from_vec(a.nrows(), b.ncols(), c));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    let mut out = None;
+    let _ = typed::<f64, _, _, _>(a, b, &mut out) || typed::<f32, _, _, _>(a, b, &mut out);
+    out
+}
+
+fn typed<E, T, U, W, const M: usize, const P: usize, const N: usize>(
+    a: &Matrix<T, M, P>,
+    b: &Matrix<U, P, N>,
+    out: &mut Option<Matrix<W, M, N>>,
+) -> bool
+where
+    E: Element,
+    T: 'static,
+    U: 'static,
+    W: 'static,
+{
+    let a = (a as &dyn Any).downcast_ref::<Matrix<E, M, P>>();
+    let b = (b as &dyn Any).downcast_ref::<Matrix<E, P, N>>();
+    let out = (out as &mut dyn Any).downcast_mut::<Option<Matrix<E, M, N>>>();
+
+    match (a, b, out) {
+        (Some(a), Some(b), Some(out)) => {
+            let mut c = Matrix([[E::default(); N]; M]);
+            gemm(
+                M,
+                P,
+                N,
+                a.0.as_flattened(),
+                b.0.as_flattened(),
+                c.0.as_flattened_mut(),
+            );
+            *out = Some(c);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Computes `C += A * B` through the accelerated kernel when `T` is `f32` or
+/// `f64`, and returns whether it did.
+pub(crate) fn try_gemm<T>(m: usize, p: usize, n: usize, a: &[T], b: &[T], c: &mut [T]) -> bool
+where
+    T: 'static,
+{
+    type Slices<'a, E> = (&'a [E], &'a [E], &'a mut [E]);
+
+    fn cast<'a, T: 'static, E: Element>(
+        a: &'a [T],
+        b: &'a [T],
+        c: &'a mut [T],
+    ) -> Option<Slices<'a, E>> {
+        (TypeId::of::<T>() == TypeId::of::<E>()).then(|| {
+            // SAFETY: `T` and `E` are the same type.
+            unsafe {
+                (
+                    &*(a as *const [T] as *const [E]),
+                    &*(b as *const [T] as *const [E]),
+                    &mut *(c as *mut [T] as *mut [E]),
+                )
+            }
+        })
+    }
+
+    if let Some((a, b, c)) = cast::<T, f64>(a, b, c) {
+        gemm(m, p, n, a, b, c);
+    } else if let Some((a, b, c)) = cast::<T, f32>(a, b, c) {
+        gemm(m, p, n, a, b, c);
+    } else {
+        return false;
+    }
+
+    true
+}
+
+/// Computes `C += A * B` for row-major `A` of `m x p` and `B` of `p x n`,
+/// handing each block of rows to a separate thread with the `rayon` feature.
+pub(crate) fn gemm<E>(m: usize, p: usize, n: usize, a: &[E], b: &[E], c: &mut [E])
+where
+    E: Element,
+{
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let mut blocks: Vec<&mut [E]> = c.chunks_mut(MC * n).collect();
+    par::for_each_mut(&mut blocks, 2, |block, c| {
+        let (i0, rows) = (block * MC, c.len() / n);
+        let a = &a[i0 * p..(i0 + rows) * p];
+        for k0 in (0..p).step_by(KC) {
+            let k1 = (k0 + KC).min(p);
+            E::block((0, rows), (k0, k1), p, n, a, b, c);
+        }
+    });
+}
+
+/// Portable kernel that keeps `MR` rows of `C` hot while streaming rows of `B`,
+/// leaving the inner loop simple enough for the compiler to vectorize.
+fn block_scalar<E>(
+    (i0, i1): (usize, usize),
+    (k0, k1): (usize, usize),
+    p: usize,
+    n: usize,
+    a: &[E],
+    b: &[E],
+    c: &mut [E],
+) where
+    E: Element,
+{
+    for i in (i0..i1).step_by(MR) {
+        let rows = MR.min(i1 - i);
+        let c_rows = &mut c[i * n..(i + rows) * n];
+
+        for k in k0..k1 {
+            let b_row = &b[k * n..(k + 1) * n];
+            for (r, c_row) in c_rows.chunks_exact_mut(n).enumerate() {
+                let aik = a[(i + r) * p + k];
+                for (c, &b) in c_row.iter_mut().zip(b_row) {
+                    *c = *c + aik * b;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! element_impl {
+    (
+        Self = $Self:ty,
+        lanes = $lanes:literal,
+        avx = $avx:ident,
+        setzero = $setzero:ident,
+        set1 = $set1:ident,
+        loadu = $loadu:ident,
+        storeu = $storeu:ident,
+        fmadd = $fmadd:ident,
+        add = $add:ident,
+    ) => {
+        impl Element for $Self {
+            fn block(
+                rows: (usize, usize),
+                depth: (usize, usize),
+                p: usize,
+                n: usize,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) {
+                #[cfg(target_arch = "x86_64")]
+                if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+                    // SAFETY: the required target features were detected above.
+                    return unsafe { $avx(rows, depth, p, n, a, b, c) };
+                }
+
+                block_scalar(rows, depth, p, n, a, b, c);
+            }
+        }
+
+        /// Register-blocked kernel that accumulates an `MR x 2 * lanes` tile of
+        /// `C` in AVX registers.
+        ///
+        /// # Safety
+        ///
+        /// The CPU must support AVX2 and FMA.
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn $avx(
+            (i0, i1): (usize, usize),
+            (k0, k1): (usize, usize),
+            p: usize,
+            n: usize,
+            a: &[$Self],
+            b: &[$Self],
+            c: &mut [$Self],
+        ) {
+            use std::arch::x86_64::*;
+
+            const NR: usize = 2 * $lanes;
+            let full_rows = i0 + (i1 - i0) / MR * MR;
+            let full_cols = n / NR * NR;
+
+            for i in (i0..full_rows).step_by(MR) {
+                for j in (0..full_cols).step_by(NR) {
+                    // SAFETY: `i + MR <= m` and `j + NR <= n`, so every load
+                    // and store below stays inside `a`, `b` and `c`.
+                    unsafe {
+                        let mut acc = [[$setzero(); 2]; MR];
+                        for k in k0..k1 {
+                            let bp = b.as_ptr().add(k * n + j);
+                            let b0 = $loadu(bp);
+                            let b1 = $loadu(bp.add($lanes));
+                            for (r, acc) in acc.iter_mut().enumerate() {
+                                let aik = $set1(*a.get_unchecked((i + r) * p + k));
+                                acc[0] = $fmadd(aik, b0, acc[0]);
+                                acc[1] = $fmadd(aik, b1, acc[1]);
+                            }
+                        }
+
+                        for (r, acc) in acc.iter().enumerate() {
+                            let cp = c.as_mut_ptr().add((i + r) * n + j);
+                            $storeu(cp, $add($loadu(cp), acc[0]));
+                            $storeu(cp.add($lanes), $add($loadu(cp.add($lanes)), acc[1]));
+                        }
+                    }
+                }
+
+                for r in 0..MR {
+                    for k in k0..k1 {
+                        let aik = a[(i + r) * p + k];
+                        for j in full_cols..n {
+                            c[(i + r) * n + j] += aik * b[k * n + j];
+                        }
+                    }
+                }
+            }
+
+            if full_rows < i1 {
+                block_scalar((full_rows, i1), (k0, k1), p, n, a, b, c);
+            }
+        }
+    };
+}
+
+element_impl! {
+    Self = f64,
+    lanes = 4,
+    avx = block_avx_f64,
+    setzero = _mm256_setzero_pd,
+    set1 = _mm256_set1_pd,
+    loadu = _mm256_loadu_pd,
+    storeu = _mm256_storeu_pd,
+    fmadd = _mm256_fmadd_pd,
+    add = _mm256_add_pd,
+}
+
+element_impl! {
+    Self = f32,
+    lanes = 8,
+    avx = block_avx_f32,
+    setzero = _mm256_setzero_ps,
+    set1 = _mm256_set1_ps,
+    loadu = _mm256_loadu_ps,
+    storeu = _mm256_storeu_ps,
+    fmadd = _mm256_fmadd_ps,
+    add = _mm256_add_ps,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{identity::IdAdd, testing::sample};
+
+    fn naive<const M: usize, const P: usize, const N: usize>(
+        a: &Matrix<f64, M, P>,
+        b: &Matrix<f64, P, N>,
+    ) -> Matrix<f64, M, N> {
+        Matrix::id_add().into_map(|i, j, _| (0..P).fold(0.0, |sum, k| sum + a[(i, k)] * b[(k, j)]))
+    }
+
+    #[test]
+    fn f64_odd_shape() {
+        // Neither dimension is a multiple of the tile sizes, and the shared
+        // dimension spans more than one `KC` block.
+        let a = sample::<f64, 37, 301>(1);
+        let b = sample::<f64, 301, 29>(2);
+
+        let product = try_mul::<_, _, f64, 37, 301, 29>(&a, &b).unwrap();
+        product
+            .iter()
+            .zip(naive(&a, &b).iter())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-9, "{} != {}", x, y));
+    }
+
+    #[test]
+    fn f32_matches_mul() {
+        let a: Matrix<f32, 70, 33> = sample::<f64, 70, 33>(3).map(|_, _, &x| x as f32);
+        let b: Matrix<f32, 33, 45> = sample::<f64, 33, 45>(4).map(|_, _, &x| x as f32);
+        let expected = naive(&sample::<f64, 70, 33>(3), &sample::<f64, 33, 45>(4));
+
+        a.matmul(&b)
+            .iter()
+            .zip(expected.iter())
+            .for_each(|(&x, y)| assert!((x as f64 - y).abs() < 1e-3, "{} != {}", x, y));
+    }
+
+    #[test]
+    fn gemm_matches_block_scalar() {
+        // Whichever kernel this host picks must agree with the portable one.
+        let (m, p, n) = (13, 21, 19);
+        let a: Vec<f64> = (0..m * p).map(|x| (x % 11) as f64 - 5.0).collect();
+        let b: Vec<f64> = (0..p * n).map(|x| (x % 7) as f64 - 3.0).collect();
+
+        let mut fast = vec![0.0; m * n];
+        gemm(m, p, n, &a, &b, &mut fast);
+        let mut slow = vec![0.0; m * n];
+        block_scalar((0, m), (0, p), p, n, &a, &b, &mut slow);
+
+        assert_eq!(slow, fast);
+    }
+
+    #[test]
+    fn other_types() {
+        assert!(try_mul::<i64, i64, i64, 32, 32, 32>(
+            &Matrix([[1; 32]; 32]),
+            &Matrix([[1; 32]; 32])
+        )
+        .is_none());
+        assert!(
+            try_mul::<f64, f64, f64, 2, 2, 2>(&Matrix([[1.0; 2]; 2]), &Matrix([[1.0; 2]; 2]))
+                .is_none()
+        );
+    }
+}
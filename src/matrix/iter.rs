@@ -4,11 +4,11 @@ use std::ptr;
 pub mod cursor;
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
-    pub fn iter(&self) -> Iter<T, M, N> {
+    pub fn iter(&self) -> Iter<'_, T, M, N> {
         self.into_iter()
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T, M, N> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, M, N> {
         self.into_iter()
     }
 }
@@ -41,6 +41,10 @@ impl<'a, T, const M: usize, const N: usize> Iterator for Iter<'a, T, M, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if M == 0 || N == 0 {
+            return None;
+        }
+
         if self.j == N {
             if self.i == M - 1 {
                 return None;
@@ -92,6 +96,10 @@ impl<'a, T, const M: usize, const N: usize> Iterator for IterMut<'a, T, M, N> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if M == 0 || N == 0 {
+            return None;
+        }
+
         if self.j == N {
             if self.i == M - 1 {
                 return None;
@@ -140,6 +148,10 @@ impl<T, const M: usize, const N: usize> Iterator for IntoIter<T, M, N> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if M == 0 || N == 0 {
+            return None;
+        }
+
         if self.j == N {
             if self.i == M - 1 {
                 return None;
@@ -241,4 +253,19 @@ mod tests {
         assert_eq!(0, iter.len());
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn iter_empty() {
+        let mut mat = Matrix::<i32, 0, 0>::from_rows([]);
+        assert_eq!(0, mat.iter().len());
+        assert_eq!(None, mat.iter().next());
+        assert_eq!(None, mat.iter_mut().next());
+        assert_eq!(None, mat.into_iter().next());
+
+        let mut mat = Matrix::<i32, 3, 0>::from_rows([[], [], []]);
+        assert_eq!(0, mat.iter().len());
+        assert_eq!(None, mat.iter().next());
+        assert_eq!(None, mat.iter_mut().next());
+        assert_eq!(None, mat.into_iter().next());
+    }
 }
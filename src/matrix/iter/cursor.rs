@@ -25,6 +25,9 @@ where
     type Item = (usize, usize, I::Item);
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Only advance the position once an element is known to exist, so an
+        // exhausted cursor stays put and `size_hint` never underflows.
+        let x = self.iter.next()?;
         if self.j == N {
             self.i += 1;
             self.j = 0;
@@ -33,7 +36,7 @@ where
         let j = self.j;
         self.j += 1;
 
-        self.iter.next().map(|x| (self.i, j, x))
+        Some((self.i, j, x))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -98,4 +101,19 @@ mod tests {
         assert_eq!(0, iter.len());
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn cursor_empty() {
+        let mat = Matrix::<i32, 0, 0>::from_rows([]);
+        let mut iter = mat.iter().cursor();
+        assert_eq!(0, iter.len());
+        assert_eq!(None, iter.next());
+        assert_eq!(0, iter.len());
+
+        let mat = Matrix::<i32, 3, 0>::from_rows([[], [], []]);
+        let mut iter = mat.iter().cursor();
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(0, iter.len());
+    }
 }
@@ -0,0 +1,165 @@
+use super::{solve::SolveError, Matrix};
+use crate::{identity::IdMul, scalar::Real};
+
+/// LDLᵀ decomposition of a symmetric positive semi-definite matrix, `A = L * D * Lᵀ`.
+#[derive(Debug, Clone)]
+pub struct Ldl<T, const M: usize> {
+    l: Matrix<T, M, M>,
+    d: [T; M],
+}
+
+impl<T, const M: usize> Ldl<T, M>
+where
+    T: Real,
+{
+    pub fn new(a: Matrix<T, M, M>) -> Option<Self> {
+        if !a.is_symmetric() {
+            return None;
+        }
+
+        let tol = T::EPSILON * T::from_usize(M) * a.max_abs();
+        let mut l = Matrix::<T, M, M>::id_mul();
+        let mut d = [T::id_add(); M];
+
+        for j in 0..M {
+            let dj = (0..j).fold(a[(j, j)], |dj, k| dj - l[(j, k)] * l[(j, k)] * d[k]);
+            if dj < -tol {
+                return None;
+            }
+
+            for i in j + 1..M {
+                let s = (0..j).fold(a[(i, j)], |s, k| s - l[(i, k)] * l[(j, k)] * d[k]);
+                if dj <= tol {
+                    if s.abs() > tol {
+                        return None;
+                    }
+                } else {
+                    l[(i, j)] = s / dj;
+                }
+            }
+
+            if dj > tol {
+                d[j] = dj;
+            }
+        }
+
+        Some(Self { l, d })
+    }
+
+    pub fn l(&self) -> &Matrix<T, M, M> {
+        &self.l
+    }
+
+    pub fn d(&self) -> &[T; M] {
+        &self.d
+    }
+
+    pub fn det(&self) -> T {
+        self.d.iter().fold(T::id_mul(), |det, &x| det * x)
+    }
+
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<T, M, K>,
+    ) -> Result<Matrix<T, M, K>, SolveError> {
+        if self.d.iter().any(|&x| x == T::id_add()) {
+            return Err(SolveError::Singular);
+        }
+
+        let mut x = b.clone();
+        for c in 0..K {
+            for i in 0..M {
+                for k in 0..i {
+                    x[(i, c)] = x[(i, c)] - self.l[(i, k)] * x[(k, c)];
+                }
+            }
+
+            for i in 0..M {
+                x[(i, c)] = x[(i, c)] / self.d[i];
+            }
+
+            for i in (0..M).rev() {
+                for k in i + 1..M {
+                    x[(i, c)] = x[(i, c)] - self.l[(k, i)] * x[(k, c)];
+                }
+            }
+        }
+
+        Ok(x)
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn ldl(self) -> Option<Ldl<T, M>> {
+        Ldl::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn factors() {
+        let mat = Matrix::from_rows([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let ldl = mat.ldl().unwrap();
+
+        assert_approx(
+            Matrix::from_rows([[1.0, 0.0, 0.0], [3.0, 1.0, 0.0], [-4.0, 5.0, 1.0]]),
+            ldl.l().clone(),
+            1e-12,
+        );
+        assert_eq!(&[4.0, 1.0, 9.0], ldl.d());
+        assert_eq!(36.0, ldl.det());
+    }
+
+    #[test]
+    fn semidefinite() {
+        let mat = Matrix::from_rows([[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+        let ldl = mat.clone().ldl().unwrap();
+        let d = Matrix::from_rows([
+            [ldl.d()[0], 0.0, 0.0],
+            [0.0, ldl.d()[1], 0.0],
+            [0.0, 0.0, ldl.d()[2]],
+        ]);
+
+        assert_eq!(&[1.0, 0.0, 2.0], ldl.d());
+        assert_approx(
+            mat,
+            ldl.l().clone() * d * ldl.l().clone().transpose(),
+            1e-12,
+        );
+        assert_eq!(
+            Err(SolveError::Singular),
+            ldl.solve(&Matrix::from_rows([[1.0], [1.0], [1.0]]))
+        );
+    }
+
+    #[test]
+    fn solve() {
+        let mat = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        assert_approx(
+            Matrix::from_rows([[1.0], [2.0], [3.0]]),
+            mat.ldl()
+                .unwrap()
+                .solve(&Matrix::from_rows([[0.0], [0.0], [4.0]]))
+                .unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn indefinite() {
+        let mat = Matrix::from_rows([[1.0, 2.0], [2.0, 1.0]]);
+        assert!(mat.ldl().is_none());
+    }
+}
@@ -0,0 +1,234 @@
+use super::{solve::SolveError, Matrix};
+use crate::{identity::IdMul, scalar::Real};
+use std::{array, cmp::Ordering};
+
+/// LU decomposition with partial pivoting, `P * A = L * U`.
+#[derive(Debug, Clone)]
+pub struct Lu<T, const M: usize> {
+    lu: Matrix<T, M, M>,
+    perm: [usize; M],
+    sign: T,
+    tol: T,
+}
+
+impl<T, const M: usize> Lu<T, M>
+where
+    T: Real,
+{
+    pub fn new(lu: Matrix<T, M, M>) -> Self {
+        Self::factor(lu, |rest, pivot, k| {
+            rest.iter_mut().for_each(|row| eliminate(row, pivot, k));
+        })
+    }
+
+    /// Runs the elimination, handing the rows below each pivot to `rows`.
+    pub(crate) fn factor<F>(mut lu: Matrix<T, M, M>, rows: F) -> Self
+    where
+        F: Fn(&mut [[T; M]], &[T; M], usize),
+    {
+        let mut perm = array::from_fn(|i| i);
+        let mut sign = T::id_mul();
+
+        let tol = T::EPSILON * T::from_usize(M) * lu.max_abs();
+
+        for k in 0..M {
+            let p = lu.pivot(k);
+            if p != k {
+                lu.0.swap(p, k);
+                perm.swap(p, k);
+                sign = -sign;
+            }
+            let (top, rest) = lu.0.split_at_mut(k + 1);
+            if top[k][k].abs() <= tol {
+                // The pivot is the largest entry left in the column, so the
+                // entries below it are negligible too and their multipliers
+                // are taken to be zero.
+                rest.iter_mut().for_each(|row| row[k] = T::id_add());
+                continue;
+            }
+
+            rows(rest, &top[k], k);
+        }
+
+        Self {
+            lu,
+            perm,
+            sign,
+            tol,
+        }
+    }
+
+    /// Returns the unit lower triangular factor. If the matrix is singular,
+    /// the columns whose pivot was negligible hold zeros below the diagonal,
+    /// and `P * A = L * U` holds up to the tolerance used to detect them.
+    pub fn l(&self) -> Matrix<T, M, M> {
+        self.lu.map(|i, j, &x| match i.cmp(&j) {
+            Ordering::Greater => x,
+            Ordering::Equal => T::id_mul(),
+            Ordering::Less => T::id_add(),
+        })
+    }
+
+    /// Returns the upper triangular factor, whose diagonal holds a negligible
+    /// pivot wherever the matrix loses rank.
+    pub fn u(&self) -> Matrix<T, M, M> {
+        self.lu.map(|i, j, &x| if i <= j { x } else { T::id_add() })
+    }
+
+    pub fn p(&self) -> Matrix<T, M, M> {
+        Matrix::from_rows(array::from_fn(|i| {
+            array::from_fn(|j| {
+                if self.perm[i] == j {
+                    T::id_mul()
+                } else {
+                    T::id_add()
+                }
+            })
+        }))
+    }
+
+    pub fn perm(&self) -> &[usize; M] {
+        &self.perm
+    }
+
+    pub fn is_singular(&self) -> bool {
+        (0..M).any(|k| self.lu[(k, k)].abs() <= self.tol)
+    }
+
+    pub fn det(&self) -> T {
+        (0..M).fold(self.sign, |det, k| det * self.lu[(k, k)])
+    }
+
+    pub fn solve<const K: usize>(
+        &self,
+        b: &Matrix<T, M, K>,
+    ) -> Result<Matrix<T, M, K>, SolveError> {
+        if self.is_singular() {
+            return Err(SolveError::Singular);
+        }
+
+        let mut x = Matrix::from_rows(array::from_fn(|i| b.0[self.perm[i]]));
+        for c in 0..K {
+            for i in 0..M {
+                for k in 0..i {
+                    x[(i, c)] = x[(i, c)] - self.lu[(i, k)] * x[(k, c)];
+                }
+            }
+
+            for i in (0..M).rev() {
+                for k in i + 1..M {
+                    x[(i, c)] = x[(i, c)] - self.lu[(i, k)] * x[(k, c)];
+                }
+                x[(i, c)] = x[(i, c)] / self.lu[(i, i)];
+            }
+        }
+
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T, M, M>> {
+        self.solve(&Matrix::id_mul()).ok()
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn lu(self) -> Lu<T, M> {
+        Lu::new(self)
+    }
+}
+
+/// Subtracts the multiple of `pivot` that clears column `k` of `row`, storing
+/// the multiplier in its place.
+pub(crate) fn eliminate<T, const M: usize>(row: &mut [T; M], pivot: &[T; M], k: usize)
+where
+    T: Real,
+{
+    let factor = row[k] / pivot[k];
+    row[k] = factor;
+    for (x, &p) in row[k + 1..].iter_mut().zip(&pivot[k + 1..]) {
+        *x = *x - factor * p;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn factors() {
+        let mat = Matrix::from_rows([
+            [1.0, 2.0, 0.0, 3.0],
+            [4.0, 1.0, 2.0, 0.0],
+            [0.0, 3.0, 5.0, 1.0],
+            [2.0, 0.0, 1.0, 6.0],
+        ]);
+        let lu = mat.clone().lu();
+
+        assert_approx(lu.p() * mat, lu.l() * lu.u(), 1e-12);
+        assert_eq!([1, 2, 0, 3], *lu.perm());
+    }
+
+    #[test]
+    fn det() {
+        let mat = Matrix::from_rows([[0.0, -1.0, 2.0], [3.0, 2.0, 1.0], [-2.0, -3.0, 4.0]]);
+        assert!((4.0_f64 - mat.lu().det()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn solve() {
+        let lu = Matrix::from_rows([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]).lu();
+
+        assert_approx(
+            Matrix::from_rows([[2.0], [3.0], [-1.0]]),
+            lu.solve(&Matrix::from_rows([[8.0], [-11.0], [-3.0]]))
+                .unwrap(),
+            1e-12,
+        );
+        assert_approx(
+            Matrix::from_rows([[1.0, 2.0], [0.0, 3.0], [1.0, -1.0]]),
+            lu.solve(&Matrix::from_rows([[1.0, 8.0], [-1.0, -11.0], [0.0, -3.0]]))
+                .unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let mat = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        assert_approx(
+            Matrix::from_rows([[0.75, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.75]]),
+            mat.lu().inverse().unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn singular() {
+        let lu = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]).lu();
+
+        assert!(lu.is_singular());
+        assert_eq!(0.0, lu.det());
+        assert_eq!(None, lu.inverse());
+    }
+
+    #[test]
+    fn singular_factors() {
+        // The second column is a tenth of the first, which elimination only
+        // clears up to rounding.
+        let mat = Matrix::from_rows([
+            [0.1, 0.1 * 0.1, 1.0],
+            [0.3, 0.3 * 0.1, 2.0],
+            [0.2, 0.2 * 0.1, 5.0],
+        ]);
+        let lu = mat.clone().lu();
+
+        assert!(lu.is_singular());
+        assert_eq!(0.0, lu.l()[(2, 1)]);
+        assert_approx(lu.p() * mat, lu.l() * lu.u(), 1e-12);
+    }
+}
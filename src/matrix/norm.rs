@@ -0,0 +1,109 @@
+use super::Matrix;
+use crate::scalar::{Real, Scalar};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Scalar,
+{
+    /// Returns the maximum absolute column sum.
+    pub fn norm_1(&self) -> T {
+        (0..N)
+            .map(|j| (0..M).fold(T::id_add(), |sum, i| sum + self[(i, j)].abs()))
+            .fold(T::id_add(), |max, x| if x > max { x } else { max })
+    }
+
+    /// Returns the maximum absolute row sum.
+    pub fn norm_inf(&self) -> T {
+        (0..M)
+            .map(|i| (0..N).fold(T::id_add(), |sum, j| sum + self[(i, j)].abs()))
+            .fold(T::id_add(), |max, x| if x > max { x } else { max })
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Real,
+{
+    /// Returns the square root of the sum of the squared entries.
+    pub fn norm_fro(&self) -> T {
+        self.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt()
+    }
+
+    /// Returns the spectral norm, which is the largest singular value.
+    pub fn norm_2(&self) -> T {
+        self.clone()
+            .svd()
+            .singular_values()
+            .first()
+            .copied()
+            .unwrap_or(T::id_add())
+    }
+
+    /// Returns the ratio of the largest to the smallest singular value. It is
+    /// infinite only when the smallest singular value is exactly zero; a
+    /// rank-deficient matrix usually gives a very large finite value instead,
+    /// because rounding leaves that singular value slightly above zero.
+    pub fn cond(&self) -> T {
+        let svd = self.clone().svd();
+        match svd.singular_values() {
+            [] => T::id_mul(),
+            [.., min] if *min == T::id_add() => T::from_f64(f64::INFINITY),
+            s => s[0] / s[s.len() - 1],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::IdAdd;
+
+    fn sample() -> Matrix<f64, 2, 3> {
+        Matrix::from_rows([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]])
+    }
+
+    #[test]
+    fn norm_1() {
+        assert_eq!(9.0, sample().norm_1());
+        assert_eq!(9, Matrix::from_rows([[1, -2, 3], [-4, 5, -6]]).norm_1());
+    }
+
+    #[test]
+    fn norm_inf() {
+        assert_eq!(15.0, sample().norm_inf());
+        assert_eq!(15, Matrix::from_rows([[1, -2, 3], [-4, 5, -6]]).norm_inf());
+    }
+
+    #[test]
+    fn norm_fro() {
+        assert!((91.0_f64.sqrt() - sample().norm_fro()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn norm_2() {
+        let mat = Matrix::from_rows([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        assert!((5.0_f64 - mat.norm_2()).abs() < 1e-12);
+        assert!(mat.norm_2() <= mat.norm_fro());
+    }
+
+    #[test]
+    fn cond() {
+        let mat = Matrix::from_rows([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        assert!((5.0_f64 / 3.0 - mat.cond()).abs() < 1e-12);
+
+        let hilbert: Matrix<f64, 4, 4> =
+            Matrix::id_add().into_map(|i, j, _| 1.0 / (i + j + 1) as f64);
+        assert!((1.5514e4 - hilbert.cond()).abs() < 1.0);
+
+        assert!(Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]).cond() > 1e15);
+    }
+
+    #[test]
+    fn cond_singular() {
+        assert_eq!(f64::INFINITY, Matrix::<f64, 3, 3>::id_add().cond());
+        assert_eq!(
+            f64::INFINITY,
+            Matrix::from_rows([[1.0, 0.0], [0.0, 0.0]]).cond()
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use super::Matrix;
-use crate::{identity::IdAdd, recip::Recip};
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
-use std::ptr;
+use super::{gemm, Matrix};
+use crate::{
+    identity::{IdAdd, IdMul},
+    recip::Recip,
+    scalar::{Real, Scalar},
+};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn scale<U, V>(&self, factor: U) -> Matrix<V, M, N>
@@ -20,47 +23,310 @@ impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T, const M: usize> Matrix<T, M, M>
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
-    T: Clone + Add<T, Output = T> + IdAdd,
+    T: Scalar,
 {
-    pub fn pow(self, exp: u32) -> Self {
-        (0..exp).fold(self.clone(), |acc, _| acc * self.clone())
+    pub(crate) fn max_abs(&self) -> T {
+        self.iter().fold(
+            T::id_add(),
+            |max, x| {
+                if x.abs() > max {
+                    x.abs()
+                } else {
+                    max
+                }
+            },
+        )
+    }
+
+    /// Rotates columns `p` and `q` by the Givens rotation `[[c, s], [-s, c]]`.
+    pub(crate) fn rotate_cols(&mut self, p: usize, q: usize, c: T, s: T) {
+        for i in 0..M {
+            let (x, y) = (self[(i, p)], self[(i, q)]);
+            self[(i, p)] = c * x - s * y;
+            self[(i, q)] = s * x + c * y;
+        }
+    }
+
+    /// Rotates rows `p` and `q` by the transpose of the rotation in [`Self::rotate_cols`].
+    pub(crate) fn rotate_rows(&mut self, p: usize, q: usize, c: T, s: T) {
+        for j in 0..N {
+            let (x, y) = (self[(p, j)], self[(q, j)]);
+            self[(p, j)] = c * x - s * y;
+            self[(q, j)] = s * x + c * y;
+        }
     }
 }
 
-impl<T> Matrix<T, 1, 1>
+impl<T, const M: usize, const P: usize> Matrix<T, M, P>
 where
-    T: ToOwned,
+    T: Scalar + 'static,
 {
-    pub fn det(&self) -> T::Owned {
-        self[(0, 0)].to_owned()
+    /// Computes `self * rhs`, handing large `f32` and `f64` products to a
+    /// blocked SIMD kernel. The `*` operator stays generic over any element
+    /// type and always takes the portable path.
+    pub fn matmul<const N: usize>(&self, rhs: &Matrix<T, P, N>) -> Matrix<T, M, N> {
+        gemm::try_mul(self, rhs).unwrap_or_else(|| {
+            Matrix::id_add().into_map(|i, j, _| {
+                self.0[i]
+                    .iter()
+                    .zip(&rhs.0)
+                    .fold(T::id_add(), |x, (&a, row)| x + a * row[j])
+            })
+        })
     }
 }
 
-impl<T> Matrix<T, 2, 2>
+impl<T, const M: usize> Matrix<T, M, M>
 where
     for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
-    T: Sub<T, Output = T>,
+    T: Clone + Add<T, Output = T> + IdAdd + IdMul,
 {
-    pub fn det(&self) -> T {
-        &self[(0, 0)] * &self[(1, 1)] - &self[(0, 1)] * &self[(1, 0)]
+    /// Raises the matrix to the power of `exp` by repeated squaring, so that
+    /// `pow(0)` is the identity.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::id_mul();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+
+        acc
     }
 }
 
-impl<T> Matrix<T, 3, 3>
+impl<T, const M: usize> Matrix<T, M, M>
 where
     for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
-    for<'a> T: Add<T, Output = T> + Sub<T, Output = T> + Mul<&'a T, Output = T>,
+    T: Real,
 {
+    /// Raises the matrix to the power of `exp`, inverting it first when `exp`
+    /// is negative, or returns `None` if it is singular in that case.
+    pub fn try_powi(self, exp: i32) -> Option<Self> {
+        let base = if exp < 0 { self.try_recip()? } else { self };
+        Some(base.pow(exp.unsigned_abs()))
+    }
+
+    pub fn powi(self, exp: i32) -> Self {
+        self.try_powi(exp)
+            .expect("cannot raise a singular matrix to a negative power")
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Scalar + Rem<T, Output = T>,
+{
+    /// Raises the matrix to the power of `exp` with every entry reduced modulo
+    /// `modulus` into `[0, modulus)`.
+    ///
+    /// Intermediate products are only reduced after multiplying two entries,
+    /// so `modulus * modulus` must fit in `T`.
+    pub fn pow_mod(self, mut exp: u64, modulus: T) -> Self {
+        assert!(
+            modulus > T::id_add(),
+            "cannot reduce modulo a non-positive number"
+        );
+
+        let reduce = |x: T| ((x % modulus) + modulus) % modulus;
+        let mul = |a: &Self, b: &Self| {
+            a.map(|i, j, _| {
+                (0..M).fold(T::id_add(), |sum, k| {
+                    reduce(sum + reduce(a[(i, k)] * b[(k, j)]))
+                })
+            })
+        };
+
+        let mut base = self.map(|_, _, &x| reduce(x));
+        let mut acc = Self::id_mul().map(|_, _, &x| reduce(x));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = mul(&acc, &base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = mul(&base, &base);
+            }
+        }
+
+        acc
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    for<'a> T: Add<&'a T, Output = T>,
+    T: IdAdd,
+{
+    pub fn trace(&self) -> T {
+        self.diag().into_iter().fold(T::id_add(), |acc, x| acc + x)
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Scalar,
+{
+    /// Returns the determinant, by LU with partial pivoting for floating-point
+    /// scalars and by fraction-free Bareiss elimination for exact ones, so that
+    /// integer determinants never round.
     pub fn det(&self) -> T {
-        &self[(0, 0)] * &self[(1, 1)] * &self[(2, 2)]
-            + &self[(0, 1)] * &self[(1, 2)] * &self[(2, 0)]
-            + &self[(0, 2)] * &self[(1, 0)] * &self[(2, 1)]
-            - &self[(0, 2)] * &self[(1, 1)] * &self[(2, 0)]
-            - &self[(0, 0)] * &self[(1, 2)] * &self[(2, 1)]
-            - &self[(0, 1)] * &self[(1, 0)] * &self[(2, 2)]
+        if T::EXACT {
+            self.det_bareiss()
+        } else {
+            self.det_lu()
+        }
+    }
+
+    /// Returns the determinant of the submatrix without row `i` and column `j`.
+    pub fn minor(&self, i: usize, j: usize) -> T {
+        if (i + j).is_multiple_of(2) {
+            self.cofactor(i, j)
+        } else {
+            -self.cofactor(i, j)
+        }
+    }
+
+    pub fn cofactor(&self, i: usize, j: usize) -> T {
+        assert!(
+            i < M && j < M,
+            "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+            M,
+            M,
+            i,
+            j
+        );
+
+        // Expanding along row `i` once it is replaced by the unit vector `e_j`
+        // leaves exactly the cofactor of `(i, j)`.
+        let mut a = self.clone();
+        for k in 0..M {
+            a[(i, k)] = if k == j { T::id_mul() } else { T::id_add() };
+        }
+
+        a.det()
+    }
+
+    /// Returns the adjugate, so that `A * adj(A) = det(A) * I`.
+    pub fn adjugate(&self) -> Self {
+        self.map(|i, j, _| self.cofactor(j, i))
+    }
+
+    pub(crate) fn pivot(&self, k: usize) -> usize {
+        (k + 1..M).fold(k, |p, i| {
+            if self[(i, k)].abs() > self[(p, k)].abs() {
+                i
+            } else {
+                p
+            }
+        })
+    }
+
+    fn det_lu(&self) -> T {
+        let mut a = self.clone();
+        let mut det = T::id_mul();
+
+        for k in 0..M {
+            let p = a.pivot(k);
+            if a[(p, k)] == T::id_add() {
+                return T::id_add();
+            }
+            if p != k {
+                a.0.swap(p, k);
+                det = -det;
+            }
+
+            det = det * a[(k, k)];
+            for i in k + 1..M {
+                let factor = a[(i, k)] / a[(k, k)];
+                for j in k + 1..M {
+                    a[(i, j)] = a[(i, j)] - factor * a[(k, j)];
+                }
+            }
+        }
+
+        det
+    }
+
+    fn det_bareiss(&self) -> T {
+        let mut a = self.clone();
+        let mut sign = T::id_mul();
+        let mut prev = T::id_mul();
+
+        for k in 0..M {
+            if a[(k, k)] == T::id_add() {
+                match (k + 1..M).find(|&i| a[(i, k)] != T::id_add()) {
+                    Some(p) => {
+                        a.0.swap(p, k);
+                        sign = -sign;
+                    }
+                    None => return T::id_add(),
+                }
+            }
+
+            // Every division is exact: the quotient is a minor of the original matrix.
+            for i in k + 1..M {
+                for j in k + 1..M {
+                    a[(i, j)] = (a[(i, j)] * a[(k, k)] - a[(i, k)] * a[(k, j)]) / prev;
+                }
+            }
+            prev = a[(k, k)];
+        }
+
+        sign * prev
+    }
+
+    /// Gauss–Jordan elimination with partial pivoting, giving up once a pivot
+    /// is negligible next to the largest entry.
+    fn gauss_jordan(&self) -> Option<Self> {
+        let mut a = self.clone();
+        let mut inv = Self::id_mul();
+
+        let tol = T::EPSILON * T::from_usize(M) * a.max_abs();
+
+        for k in 0..M {
+            let p = a.pivot(k);
+            if a[(p, k)].abs() <= tol {
+                return None;
+            }
+            a.0.swap(p, k);
+            inv.0.swap(p, k);
+
+            let pivot = a[(k, k)];
+            for j in 0..M {
+                a[(k, j)] = a[(k, j)] / pivot;
+                inv[(k, j)] = inv[(k, j)] / pivot;
+            }
+
+            for i in (0..M).filter(|&i| i != k) {
+                let factor = a[(i, k)];
+                for j in 0..M {
+                    a[(i, j)] = a[(i, j)] - factor * a[(k, j)];
+                    inv[(i, j)] = inv[(i, j)] - factor * inv[(k, j)];
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    /// Inverts the matrix by Gauss–Jordan elimination, or returns `None` if it
+    /// is singular or too close to singular for the result to be meaningful.
+    pub fn try_recip(&self) -> Option<Self> {
+        self.gauss_jordan()
     }
 }
 
@@ -151,45 +417,36 @@ where
 {
     type Output = Matrix<W, M, N>;
 
+    /// Multiplies row by column for any element type. Large `f32` and `f64`
+    /// products are faster through [`matmul`](Matrix::matmul), which picks a
+    /// SIMD kernel when it applies.
     fn mul(self, rhs: Matrix<U, P, N>) -> Self::Output {
-        Self::Output::id_add().into_map(|i, j, mut x| {
-            for k in 0..P {
-                x = x + &self[(i, k)] * &rhs[(k, j)];
-            }
-
-            x
+        Self::Output::id_add().into_map(|i, j, x| {
+            self.0[i]
+                .iter()
+                .zip(&rhs.0)
+                .fold(x, |x, (a, row)| x + a * &row[j])
         })
     }
 }
 
-impl<T> Recip for Matrix<T, 1, 1>
+impl<T, const M: usize> Recip for Matrix<T, M, M>
 where
-    T: Recip<Output = T>,
-{
-    type Output = Matrix<T, 1, 1>;
-
-    fn recip(self) -> Self::Output {
-        Self::from_rows([[unsafe { ptr::read(&self[(0, 0)]) }.recip()]])
-    }
-}
-
-impl<T> Recip for Matrix<T, 2, 2>
-where
-    for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
-    T: Sub<T, Output = T> + Neg<Output = T> + Recip<Output = T> + IdAdd,
+    T: Scalar,
 {
     type Output = Self;
 
+    /// Inverts floating-point matrices by Gauss–Jordan elimination and exact
+    /// ones as `adj(A) / det(A)`, panicking if the matrix is singular.
     fn recip(self) -> Self::Output {
-        Self::from_rows([
-            [unsafe { ptr::read(&self[(1, 1)]) }, -unsafe {
-                ptr::read(&self[(0, 1)])
-            }],
-            [-unsafe { ptr::read(&self[(1, 0)]) }, unsafe {
-                ptr::read(&self[(0, 0)])
-            }],
-        ])
-        .scale(self.det().recip())
+        if T::EXACT {
+            let det = self.det();
+            assert!(det != T::id_add(), "cannot invert a singular matrix");
+            self.adjugate().into_map(|_, _, x| x / det)
+        } else {
+            self.gauss_jordan()
+                .expect("cannot invert a singular matrix")
+        }
     }
 }
 
@@ -216,7 +473,53 @@ mod tests {
     #[test]
     fn pow() {
         let mat = Matrix::from_rows([[1, 3], [2, -1]]);
-        assert_eq!(Matrix::from_rows([[49, 0], [0, 49]]), mat.pow(3));
+        assert_eq!(Matrix::id_mul(), mat.clone().pow(0));
+        assert_eq!(mat.clone(), mat.clone().pow(1));
+        assert_eq!(Matrix::from_rows([[7, 21], [14, -7]]), mat.clone().pow(3));
+        assert_eq!(Matrix::from_rows([[49, 0], [0, 49]]), mat.pow(4));
+
+        let fib = Matrix::from_rows([[1_i64, 1], [1, 0]]);
+        assert_eq!(2880067194370816120, fib.pow(90)[(0, 1)]);
+    }
+
+    #[test]
+    fn powi() {
+        let mat = Matrix::from_rows([[2.0, 1.0], [0.0, 4.0]]);
+        assert_eq!(Matrix::id_mul(), mat.clone().powi(0));
+        assert_eq!(
+            Matrix::from_rows([[4.0, 6.0], [0.0, 16.0]]),
+            mat.clone().powi(2)
+        );
+        assert_eq!(
+            Matrix::from_rows([[0.25, -0.09375], [0.0, 0.0625]]),
+            mat.powi(-2)
+        );
+    }
+
+    #[test]
+    fn try_powi_singular() {
+        let mat = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(None, mat.clone().try_powi(-1));
+        assert!(mat.try_powi(3).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot raise a singular matrix to a negative power")]
+    fn powi_singular() {
+        let _ = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]).powi(-1);
+    }
+
+    #[test]
+    fn pow_mod() {
+        let fib = Matrix::from_rows([[1_i64, 1], [1, 0]]);
+        assert_eq!(517691607, fib.pow_mod(1000, 1_000_000_007)[(0, 1)]);
+
+        let mat = Matrix::from_rows([[-1, 2], [3, 0]]);
+        assert_eq!(
+            Matrix::from_rows([[1, 0], [0, 1]]),
+            mat.clone().pow_mod(0, 5)
+        );
+        assert_eq!(Matrix::from_rows([[2, 3], [2, 1]]), mat.pow_mod(2, 5));
     }
 
     #[test]
@@ -237,6 +540,99 @@ mod tests {
         assert_eq!(4, mat.det());
     }
 
+    #[test]
+    fn det4x4() {
+        let mat = Matrix::from_rows([[3, 2, 0, 1], [4, 0, 1, 2], [3, 0, 2, 1], [9, 2, 3, 1]]);
+        assert_eq!(24, mat.det());
+    }
+
+    #[test]
+    fn det5x5_exact() {
+        let mat = Matrix::<i64, 5, 5>::from_rows([
+            [0, 2, -1, 4, 3],
+            [1, 0, 2, -3, 1],
+            [2, 1, 0, 1, -2],
+            [-1, 3, 1, 0, 2],
+            [3, -2, 4, 1, 0],
+        ]);
+        assert_eq!(498, mat.det());
+    }
+
+    #[test]
+    fn det4x4_float() {
+        let mat = Matrix::from_rows([
+            [3.0, 2.0, 0.0, 1.0],
+            [4.0, 0.0, 1.0, 2.0],
+            [3.0, 0.0, 2.0, 1.0],
+            [9.0, 2.0, 3.0, 1.0],
+        ]);
+        assert!((24.0 - mat.det()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn det_matches_lu() {
+        let mat: Matrix<f64, 6, 6> =
+            Matrix::id_mul().into_map(|i, j, x| ((i * 7 + j * 3) % 11) as f64 - 5.0 + 9.0 * x);
+        let expected = mat.clone().lu().det();
+
+        assert!((expected - mat.det()).abs() < 1e-9 * expected.abs());
+    }
+
+    #[test]
+    fn det_singular() {
+        let mat = Matrix::from_rows([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(0, mat.det());
+
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 5.0]]);
+        assert_eq!(0.0, mat.det());
+    }
+
+    #[test]
+    fn trace() {
+        let mat = Matrix::from_rows([[2, 5, 7], [6, 1, -1], [3, -2, 4]]);
+        assert_eq!(7, mat.trace());
+    }
+
+    #[test]
+    fn minor() {
+        let mat = Matrix::from_rows([[1, 4, 7], [3, 0, 5], [-1, 9, 11]]);
+
+        assert_eq!(-45, mat.minor(0, 0));
+        assert_eq!(38, mat.minor(0, 1));
+        assert_eq!(13, mat.minor(1, 2));
+        assert_eq!(-13, mat.cofactor(1, 2));
+        assert_eq!(-38, mat.cofactor(0, 1));
+    }
+
+    #[test]
+    fn adjugate() {
+        let mat = Matrix::from_rows([[-3, 2, -5], [-1, 0, -2], [3, -4, 1]]);
+
+        assert_eq!(
+            Matrix::from_rows([[-8, 18, -4], [-5, 12, -1], [4, -6, 2]]),
+            mat.adjugate()
+        );
+        assert_eq!(
+            Matrix::from_rows([[4, -2], [-3, 1]]),
+            Matrix::from_rows([[1, 2], [3, 4]]).adjugate()
+        );
+    }
+
+    #[test]
+    fn adjugate5x5() {
+        let mat = Matrix::<i64, 5, 5>::from_rows([
+            [0, 2, -1, 4, 3],
+            [1, 0, 2, -3, 1],
+            [2, 1, 0, 1, -2],
+            [-1, 3, 1, 0, 2],
+            [3, -2, 4, 1, 0],
+        ]);
+        let adj = mat.adjugate();
+
+        assert_eq!(mat.map(|i, j, _| mat.cofactor(j, i)), adj);
+        assert_eq!(Matrix::id_mul().scale(498_i64), mat * adj);
+    }
+
     #[test]
     fn add() {
         let mut mat1 = Matrix::from_rows([[8, 6, -1], [0, 7, 2], [4, 4, 5], [-3, -5, 3]]);
@@ -292,6 +688,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mul_borrowed() {
+        // Elements that borrow data must not need to be `'static`.
+        struct Borrowed<'a>(&'a i32);
+
+        impl Mul<&Borrowed<'_>> for &Borrowed<'_> {
+            type Output = i32;
+
+            fn mul(self, rhs: &Borrowed<'_>) -> i32 {
+                self.0 * rhs.0
+            }
+        }
+
+        let (x, y) = (3, 4);
+        let product = Matrix::from_rows([[Borrowed(&x)]]) * Matrix::from_rows([[Borrowed(&y)]]);
+        assert_eq!(Matrix::from_rows([[12]]), product);
+    }
+
+    #[test]
+    fn matmul() {
+        let mat1 = Matrix::from_rows([[8, 6, -1], [0, 7, 2], [4, 4, 5], [-3, -5, 3]]);
+        let mat2 = Matrix::from_rows([[3, -1], [2, -3], [-2, 1]]);
+        assert_eq!(mat1.clone() * mat2.clone(), mat1.matmul(&mat2));
+
+        let mat1 = mat1.map(|_, _, &x| x as f64);
+        let mat2 = mat2.map(|_, _, &x| x as f64);
+        assert_eq!(mat1.clone() * mat2.clone(), mat1.matmul(&mat2));
+    }
+
     #[test]
     fn recip1x1() {
         let mat = Matrix::from_rows([[5.0]]);
@@ -308,4 +733,54 @@ mod tests {
                 .into_map(|_, _, x| (1000.0_f64 * x).trunc() / 1000.0)
         );
     }
+
+    #[test]
+    fn recip3x3() {
+        let mat = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        assert_eq!(
+            Matrix::from_rows([[0.75, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 0.75]]),
+            mat.recip()
+                .into_map(|_, _, x| (1000.0_f64 * x).round() / 1000.0)
+        );
+    }
+
+    #[test]
+    fn recip4x4() {
+        let mat = Matrix::from_rows([
+            [4.0, 7.0, 2.0, 3.0],
+            [0.0, 5.0, 0.0, 1.0],
+            [1.0, 0.0, 3.0, 0.0],
+            [2.0, 1.0, 0.0, 6.0],
+        ]);
+
+        let id = mat.clone() * mat.recip();
+        id.iter()
+            .zip(Matrix::<f64, 4, 4>::id_mul().iter())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-12));
+    }
+
+    #[test]
+    fn try_recip_singular() {
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(None, mat.try_recip());
+
+        let mat = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(None, mat.try_recip());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot invert a singular matrix")]
+    fn recip_singular() {
+        let mat = Matrix::from_rows([[0.0, 0.0], [0.0, 0.0]]);
+        let _ = mat.recip();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot invert a singular matrix")]
+    fn recip_rounded_singular() {
+        // Rounding may leave det() slightly off zero, but elimination still spots the rank loss.
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let _ = mat.recip();
+    }
 }
@@ -0,0 +1,201 @@
+use super::{
+    cholesky::{self, Cholesky},
+    lu::{self, Lu},
+    Matrix,
+};
+use crate::{identity::IdAdd, par, scalar::Real};
+use rayon::prelude::*;
+use std::{
+    array,
+    ops::{Add, Mul, Sub},
+};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Returns a parallel iterator over the rows.
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, [T; N]>
+    where
+        T: Sync,
+    {
+        self.0.par_iter()
+    }
+
+    /// Returns a parallel iterator over the rows, which may be modified in place.
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, [T; N]>
+    where
+        T: Send,
+    {
+        self.0.par_iter_mut()
+    }
+
+    /// Parallel counterpart of [`map`](Self::map), evaluating rows on separate threads.
+    pub fn par_map<U, F>(&self, f: F) -> Matrix<U, M, N>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(usize, usize, &T) -> U + Sync + Send,
+    {
+        collect(
+            self.par_iter()
+                .enumerate()
+                .map(|(i, row)| array::from_fn(|j| f(i, j, &row[j]))),
+        )
+    }
+
+    /// Parallel counterpart of [`map_mut`](Self::map_mut).
+    pub fn par_map_mut<F>(&mut self, f: F)
+    where
+        T: Send,
+        F: Fn(usize, usize, &mut T) + Sync + Send,
+    {
+        self.par_iter_mut().enumerate().for_each(|(i, row)| {
+            for (j, x) in row.iter_mut().enumerate() {
+                f(i, j, x);
+            }
+        });
+    }
+
+    /// Returns `self + rhs`, summing each row on a separate thread.
+    pub fn par_add<U, V>(&self, rhs: &Matrix<U, M, N>) -> Matrix<V, M, N>
+    where
+        for<'a, 'b> &'a T: Add<&'b U, Output = V>,
+        T: Sync,
+        U: Sync,
+        V: Send,
+    {
+        self.par_map(|i, j, x| x + &rhs[(i, j)])
+    }
+
+    /// Returns `self - rhs`, taking the difference of each row on a separate thread.
+    pub fn par_sub<U, V>(&self, rhs: &Matrix<U, M, N>) -> Matrix<V, M, N>
+    where
+        for<'a, 'b> &'a T: Sub<&'b U, Output = V>,
+        T: Sync,
+        U: Sync,
+        V: Send,
+    {
+        self.par_map(|i, j, x| x - &rhs[(i, j)])
+    }
+
+    /// Parallel counterpart of `*`, computing rows of the product on separate threads.
+    pub fn par_mul<U, V, W, const P: usize>(&self, rhs: &Matrix<U, N, P>) -> Matrix<W, M, P>
+    where
+        for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+        V: Add<V, Output = W>,
+        W: Add<V, Output = W> + IdAdd + Send,
+        T: Sync,
+        U: Sync,
+    {
+        collect(self.par_iter().map(|row| {
+            array::from_fn(|j| (0..N).fold(W::id_add(), |x, k| x + &row[k] * &rhs[(k, j)]))
+        }))
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real + Send + Sync,
+{
+    /// Parallel counterpart of [`lu`](Self::lu), eliminating the rows below
+    /// each pivot on separate threads.
+    pub fn par_lu(self) -> Lu<T, M> {
+        Lu::factor(self, |rest, pivot, k| {
+            par::for_each_mut(rest, par::MIN_LEN, |_, row| lu::eliminate(row, pivot, k));
+        })
+    }
+
+    /// Parallel counterpart of [`cholesky`](Self::cholesky), filling the rows
+    /// below the diagonal of each column on separate threads.
+    pub fn par_cholesky(self) -> Option<Cholesky<T, M>> {
+        Cholesky::factor(self, |rest, lj, j, a| {
+            par::for_each_mut(rest, par::MIN_LEN, |r, li| {
+                li[j] = cholesky::entry(a, li, lj, j + 1 + r, j);
+            });
+        })
+    }
+}
+
+fn collect<T, I, const M: usize, const N: usize>(rows: I) -> Matrix<T, M, N>
+where
+    T: Send,
+    I: IndexedParallelIterator<Item = [T; N]>,
+{
+    let rows: Vec<[T; N]> = rows.collect();
+    match rows.try_into() {
+        Ok(rows) => Matrix(rows),
+        Err(_) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{identity::IdMul, testing::sample};
+
+    #[test]
+    fn par_iter() {
+        let mat = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let sums: Vec<i32> = mat.par_iter().map(|row| row.iter().sum()).collect();
+
+        assert_eq!(vec![6, 15], sums);
+    }
+
+    #[test]
+    fn par_map() {
+        let mat: Matrix<i64, 70, 30> = sample(0);
+
+        assert_eq!(
+            mat.map(|i, j, &x| x * (i + j) as i64),
+            mat.par_map(|i, j, &x| x * (i + j) as i64)
+        );
+    }
+
+    #[test]
+    fn par_map_mut() {
+        let mut mat = Matrix::from_rows([[1, 2], [3, 4]]);
+        mat.par_map_mut(|i, _, x| *x *= i + 1);
+
+        assert_eq!(Matrix::from_rows([[1, 2], [6, 8]]), mat);
+    }
+
+    #[test]
+    fn par_add_sub() {
+        let a: Matrix<i64, 65, 9> = sample(0);
+        let b = a.map(|i, j, &x| x * 2 - (i * j) as i64);
+
+        assert_eq!(a.clone() + b.clone(), a.par_add(&b));
+        assert_eq!(a.clone() - b.clone(), a.par_sub(&b));
+    }
+
+    #[test]
+    fn par_mul() {
+        let a: Matrix<i64, 40, 30> = sample(1);
+        let b: Matrix<i64, 30, 20> = sample(2);
+        assert_eq!(a.clone() * b.clone(), a.par_mul(&b));
+
+        let a = a.map(|_, _, &x| x as f64);
+        let b = b.map(|_, _, &x| x as f64);
+        assert_eq!(a.clone() * b.clone(), a.par_mul(&b));
+    }
+
+    #[test]
+    fn par_lu() {
+        let a: Matrix<f64, 80, 80> = sample(3);
+        let a = a.map(|i, j, &x| if i == j { x + 100.0 } else { x });
+        let (lu, par_lu) = (a.clone().lu(), a.par_lu());
+
+        assert_eq!(lu.perm(), par_lu.perm());
+        assert_eq!(lu.u(), par_lu.u());
+        assert_eq!(lu.l(), par_lu.l());
+    }
+
+    #[test]
+    fn par_cholesky() {
+        let b: Matrix<f64, 80, 80> = sample(4);
+        let a = b.clone() * b.transpose() + Matrix::id_mul();
+
+        assert_eq!(
+            a.clone().cholesky().unwrap().l(),
+            a.par_cholesky().unwrap().l()
+        );
+    }
+}
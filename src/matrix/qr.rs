@@ -0,0 +1,195 @@
+use super::{solve::SolveError, Matrix};
+use crate::{identity::IdMul, scalar::Real};
+use std::{array, cmp::Ordering};
+
+/// QR decomposition by Householder reflections, `A = Q * R`, for `M >= N`.
+#[derive(Debug, Clone)]
+pub struct Qr<T, const M: usize, const N: usize> {
+    qr: Matrix<T, M, N>,
+    rdiag: [T; N],
+    tol: T,
+}
+
+impl<T, const M: usize, const N: usize> Qr<T, M, N>
+where
+    T: Real,
+{
+    pub fn new(mut qr: Matrix<T, M, N>) -> Self {
+        assert!(
+            M >= N,
+            "cannot take the QR decomposition of a wide matrix: {} < {}",
+            M,
+            N
+        );
+
+        let tol = T::EPSILON * T::from_usize(M) * qr.max_abs();
+        let mut rdiag = [T::id_add(); N];
+
+        for k in 0..N {
+            let mut nrm = (k..M)
+                .fold(T::id_add(), |sum, i| sum + qr[(i, k)] * qr[(i, k)])
+                .sqrt();
+
+            if nrm != T::id_add() {
+                if qr[(k, k)] < T::id_add() {
+                    nrm = -nrm;
+                }
+                for i in k..M {
+                    qr[(i, k)] = qr[(i, k)] / nrm;
+                }
+                qr[(k, k)] = qr[(k, k)] + T::id_mul();
+
+                for j in k + 1..N {
+                    let s = (k..M).fold(T::id_add(), |s, i| s + qr[(i, k)] * qr[(i, j)]);
+                    let s = -s / qr[(k, k)];
+                    for i in k..M {
+                        qr[(i, j)] = qr[(i, j)] + s * qr[(i, k)];
+                    }
+                }
+            }
+
+            rdiag[k] = -nrm;
+        }
+
+        Self { qr, rdiag, tol }
+    }
+
+    pub fn q(&self) -> Matrix<T, M, M> {
+        let mut q = Matrix::id_mul();
+        for k in (0..N).rev() {
+            self.reflect(k, &mut q);
+        }
+
+        q
+    }
+
+    pub fn r(&self) -> Matrix<T, M, N> {
+        Matrix::from_rows(array::from_fn(|i| {
+            array::from_fn(|j| match i.cmp(&j) {
+                Ordering::Less => self.qr[(i, j)],
+                Ordering::Equal => self.rdiag[i],
+                Ordering::Greater => T::id_add(),
+            })
+        }))
+    }
+
+    pub fn is_full_rank(&self) -> bool {
+        self.rdiag.iter().all(|x| x.abs() > self.tol)
+    }
+
+    /// Finds the `X` minimizing the Frobenius norm of `A * X - B`.
+    pub fn solve_least_squares<const K: usize>(
+        &self,
+        b: &Matrix<T, M, K>,
+    ) -> Result<Matrix<T, N, K>, SolveError> {
+        if !self.is_full_rank() {
+            return Err(SolveError::Singular);
+        }
+
+        let mut y = b.clone();
+        for k in 0..N {
+            self.reflect(k, &mut y);
+        }
+
+        let mut x = Matrix::from_rows(array::from_fn(|i| y.0[i]));
+        for c in 0..K {
+            for i in (0..N).rev() {
+                for j in i + 1..N {
+                    x[(i, c)] = x[(i, c)] - self.qr[(i, j)] * x[(j, c)];
+                }
+                x[(i, c)] = x[(i, c)] / self.rdiag[i];
+            }
+        }
+
+        Ok(x)
+    }
+
+    fn reflect<const K: usize>(&self, k: usize, a: &mut Matrix<T, M, K>) {
+        if self.qr[(k, k)] == T::id_add() {
+            return;
+        }
+
+        for j in 0..K {
+            let s = (k..M).fold(T::id_add(), |s, i| s + self.qr[(i, k)] * a[(i, j)]);
+            let s = -s / self.qr[(k, k)];
+            for i in k..M {
+                a[(i, j)] = a[(i, j)] + s * self.qr[(i, k)];
+            }
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Real,
+{
+    pub fn qr(self) -> Qr<T, M, N> {
+        Qr::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn factors() {
+        let mat = Matrix::from_rows([
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0],
+            [1.0, 2.0, 3.0],
+        ]);
+        let qr = mat.clone().qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert_approx(mat, q.clone() * r.clone(), 1e-12);
+        assert_approx(Matrix::id_mul(), q.clone().transpose() * q, 1e-12);
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(0.0, r[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn solve_least_squares() {
+        // Fits y = 1 + 2x through points that lie exactly on the line.
+        let mat = Matrix::from_rows([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Matrix::from_rows([[1.0], [3.0], [5.0], [7.0]]);
+
+        assert_approx(
+            Matrix::from_rows([[1.0], [2.0]]),
+            mat.qr().solve_least_squares(&b).unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn solve_least_squares_residual() {
+        let mat = Matrix::from_rows([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+        let b = Matrix::from_rows([[6.0], [0.0], [0.0]]);
+
+        assert_approx(
+            Matrix::from_rows([[5.0], [-3.0]]),
+            mat.qr().solve_least_squares(&b).unwrap(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mat = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        let b = Matrix::from_rows([[1.0], [2.0], [3.0]]);
+
+        assert_eq!(Err(SolveError::Singular), mat.qr().solve_least_squares(&b));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take the QR decomposition of a wide matrix")]
+    fn wide() {
+        let _ = Matrix::from_rows([[1.0, 2.0, 3.0]]).qr();
+    }
+}
@@ -0,0 +1,199 @@
+use super::Matrix;
+use crate::scalar::{Real, Scalar};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Scalar,
+{
+    /// Counts the linearly independent rows, treating entries within the
+    /// default tolerance of zero as zero. Integer ranks are exact.
+    pub fn rank(&self) -> usize {
+        self.rank_with_tol(self.default_tol())
+    }
+
+    /// Counts the linearly independent rows, treating entries whose magnitude
+    /// is at most `tol` as zero.
+    ///
+    /// Exact scalars are eliminated fraction-free, so no division ever truncates.
+    pub fn rank_with_tol(&self, tol: T) -> usize {
+        let mut a = self.clone();
+        let mut prev = T::id_mul();
+        let mut r = 0;
+
+        for c in 0..N {
+            if r == M {
+                break;
+            }
+
+            let p = (r + 1..M).fold(r, |p, i| {
+                if a[(i, c)].abs() > a[(p, c)].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if a[(p, c)].abs() <= tol {
+                continue;
+            }
+            a.0.swap(p, r);
+
+            for i in r + 1..M {
+                if T::EXACT {
+                    for j in c + 1..N {
+                        a[(i, j)] = (a[(i, j)] * a[(r, c)] - a[(i, c)] * a[(r, j)]) / prev;
+                    }
+                } else {
+                    let factor = a[(i, c)] / a[(r, c)];
+                    for j in c + 1..N {
+                        a[(i, j)] = a[(i, j)] - factor * a[(r, j)];
+                    }
+                }
+                a[(i, c)] = T::id_add();
+            }
+
+            prev = a[(r, c)];
+            r += 1;
+        }
+
+        r
+    }
+
+    pub(crate) fn default_tol(&self) -> T {
+        T::EPSILON * T::from_usize(M.max(N)) * self.max_abs()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Real,
+{
+    /// Returns the reduced row echelon form along with the indices of its pivot columns.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        self.rref_with_tol(self.default_tol())
+    }
+
+    /// Returns the reduced row echelon form along with the indices of its
+    /// pivot columns, treating entries whose magnitude is at most `tol` as zero.
+    pub fn rref_with_tol(&self, tol: T) -> (Self, Vec<usize>) {
+        let mut a = self.clone();
+        let mut pivots = Vec::with_capacity(M.min(N));
+
+        for c in 0..N {
+            let r = pivots.len();
+            if r == M {
+                break;
+            }
+
+            let p = (r + 1..M).fold(r, |p, i| {
+                if a[(i, c)].abs() > a[(p, c)].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if a[(p, c)].abs() <= tol {
+                for i in r..M {
+                    a[(i, c)] = T::id_add();
+                }
+                continue;
+            }
+            a.0.swap(p, r);
+
+            let pivot = a[(r, c)];
+            for j in c..N {
+                a[(r, j)] = a[(r, j)] / pivot;
+            }
+            for i in (0..M).filter(|&i| i != r) {
+                let factor = a[(i, c)];
+                for j in c..N {
+                    a[(i, j)] = a[(i, j)] - factor * a[(r, j)];
+                }
+            }
+
+            pivots.push(c);
+        }
+
+        (a, pivots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    #[test]
+    fn rref() {
+        let (rref, pivots) = Matrix::from_rows([
+            [1.0, 2.0, -1.0, -4.0],
+            [2.0, 3.0, -1.0, -11.0],
+            [-2.0, 0.0, -3.0, 22.0],
+        ])
+        .rref();
+
+        assert_eq!(vec![0, 1, 2], pivots);
+        assert_approx(
+            Matrix::from_rows([
+                [1.0, 0.0, 0.0, -8.0],
+                [0.0, 1.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, -2.0],
+            ]),
+            rref,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn rref_deficient() {
+        let (rref, pivots) = Matrix::from_rows([
+            [0.0, 1.0, 2.0, 3.0],
+            [0.0, 2.0, 4.0, 6.0],
+            [0.0, 1.0, 0.0, 1.0],
+        ])
+        .rref();
+
+        assert_eq!(vec![1, 2], pivots);
+        assert_approx(
+            Matrix::from_rows([
+                [0.0, 1.0, 0.0, 1.0],
+                [0.0, 0.0, 1.0, 1.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ]),
+            rref,
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn rank_float() {
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(2, mat.rank());
+        assert_eq!(
+            1,
+            Matrix::from_rows([[1.0, 2.0], [1.0, 2.0 + 1e-9]]).rank_with_tol(1e-6)
+        );
+        assert_eq!(2, Matrix::from_rows([[1.0, 2.0], [1.0, 2.0 + 1e-9]]).rank());
+        assert_eq!(0, Matrix::<f64, 2, 3>::from_rows([[0.0; 3]; 2]).rank());
+    }
+
+    #[test]
+    fn rank_exact() {
+        assert_eq!(
+            2,
+            Matrix::from_rows([[2, 4, 6], [1, 2, 3], [0, 1, 1]]).rank()
+        );
+        assert_eq!(
+            3,
+            Matrix::from_rows([[2, 0, 1, 3], [0, 0, 4, 1], [6, 0, 7, 2]]).rank()
+        );
+        // `det` is -1 here, which floats this large cannot resolve.
+        assert_eq!(
+            2,
+            Matrix::from_rows([
+                [1_000_000_007_i64, 1_000_000_008],
+                [1_000_000_008, 1_000_000_009]
+            ])
+            .rank()
+        );
+    }
+}
@@ -0,0 +1,423 @@
+use super::Matrix;
+use crate::{complex::Complex, identity::IdMul, scalar::Real};
+use std::{array, ops::Range};
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Reduction to upper Hessenberg form by Householder reflections, `A = Q * H * Qᵀ`.
+#[derive(Debug, Clone)]
+pub struct Hessenberg<T, const M: usize> {
+    h: Matrix<T, M, M>,
+    q: Matrix<T, M, M>,
+}
+
+impl<T, const M: usize> Hessenberg<T, M>
+where
+    T: Real,
+{
+    pub fn new(mut h: Matrix<T, M, M>) -> Self {
+        let mut q = Matrix::id_mul();
+
+        for k in 0..M.saturating_sub(2) {
+            let x: [T; M] = array::from_fn(|i| if i > k { h[(i, k)] } else { T::id_add() });
+            let Some((v, beta)) = householder(&x[k + 1..]) else {
+                continue;
+            };
+
+            reflect_rows(&mut h, k + 1, &v, beta, k..M);
+            reflect_cols(&mut h, k + 1, &v, beta, 0..M);
+            reflect_cols(&mut q, k + 1, &v, beta, 0..M);
+            for i in k + 2..M {
+                h[(i, k)] = T::id_add();
+            }
+        }
+
+        Self { h, q }
+    }
+
+    pub fn h(&self) -> &Matrix<T, M, M> {
+        &self.h
+    }
+
+    pub fn q(&self) -> &Matrix<T, M, M> {
+        &self.q
+    }
+}
+
+/// Real Schur decomposition, `A = Q * T * Qᵀ`, where `T` is quasi-upper
+/// triangular with 1x1 blocks for real eigenvalues and 2x2 blocks for
+/// complex-conjugate pairs.
+#[derive(Debug, Clone)]
+pub struct Schur<T, const M: usize> {
+    t: Matrix<T, M, M>,
+    q: Matrix<T, M, M>,
+}
+
+impl<T, const M: usize> Schur<T, M>
+where
+    T: Real,
+{
+    /// Returns `None` if the shifted QR iteration fails to converge.
+    pub fn new(a: Matrix<T, M, M>) -> Option<Self> {
+        let Hessenberg { mut h, mut q } = Hessenberg::new(a);
+        francis(&mut h, Some(&mut q))?;
+
+        Some(Self { t: h, q })
+    }
+
+    pub fn t(&self) -> &Matrix<T, M, M> {
+        &self.t
+    }
+
+    pub fn q(&self) -> &Matrix<T, M, M> {
+        &self.q
+    }
+
+    pub fn eigenvalues(&self) -> [Complex<T>; M] {
+        eigenvalues(&self.t)
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn hessenberg(self) -> Hessenberg<T, M> {
+        Hessenberg::new(self)
+    }
+
+    pub fn schur(self) -> Option<Schur<T, M>> {
+        Schur::new(self)
+    }
+
+    /// Computes the eigenvalues without accumulating the Schur vectors.
+    pub fn eigenvalues(self) -> Option<[Complex<T>; M]> {
+        let mut h = Hessenberg::new(self).h;
+        francis(&mut h, None)?;
+
+        Some(eigenvalues(&h))
+    }
+}
+
+/// Reduces an upper Hessenberg `h` to real Schur form by Francis double-shift
+/// QR steps, accumulating the transformations into `q` if given.
+fn francis<T, const M: usize>(
+    h: &mut Matrix<T, M, M>,
+    mut q: Option<&mut Matrix<T, M, M>>,
+) -> Option<()>
+where
+    T: Real,
+{
+    let norm = h.max_abs();
+    let mut hi = M.saturating_sub(1);
+    let mut iter = 0;
+
+    while hi > 0 {
+        let mut l = hi;
+        while l > 0 {
+            let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+            if s == T::id_add() {
+                s = norm;
+            }
+            if h[(l, l - 1)].abs() <= T::EPSILON * s {
+                break;
+            }
+            l -= 1;
+        }
+        if l > 0 {
+            h[(l, l - 1)] = T::id_add();
+        }
+
+        if l == hi {
+            hi -= 1;
+            iter = 0;
+            continue;
+        }
+        if l + 1 == hi {
+            split(h, q.as_deref_mut(), l);
+            hi = hi.saturating_sub(2);
+            iter = 0;
+            continue;
+        }
+
+        iter += 1;
+        if iter > MAX_ITERATIONS {
+            return None;
+        }
+
+        let (s, t) = if iter % 10 == 0 {
+            let e = h[(hi, hi - 1)].abs() + h[(hi - 1, hi - 2)].abs();
+            (T::from_f64(1.5) * e, e * e)
+        } else {
+            (
+                h[(hi - 1, hi - 1)] + h[(hi, hi)],
+                h[(hi - 1, hi - 1)] * h[(hi, hi)] - h[(hi - 1, hi)] * h[(hi, hi - 1)],
+            )
+        };
+
+        let mut x = h[(l, l)] * h[(l, l)] + h[(l, l + 1)] * h[(l + 1, l)] - s * h[(l, l)] + t;
+        let mut y = h[(l + 1, l)] * (h[(l, l)] + h[(l + 1, l + 1)] - s);
+        let mut z = h[(l + 1, l)] * h[(l + 2, l + 1)];
+
+        for k in l..hi - 1 {
+            if let Some((v, beta)) = householder(&[x, y, z]) {
+                let r = if k > l { k - 1 } else { l };
+                reflect_rows(h, k, &v, beta, r..M);
+                reflect_cols(h, k, &v, beta, 0..(k + 4).min(hi + 1));
+                if let Some(q) = q.as_deref_mut() {
+                    reflect_cols(q, k, &v, beta, 0..M);
+                }
+            }
+
+            x = h[(k + 1, k)];
+            y = h[(k + 2, k)];
+            if k + 3 <= hi {
+                z = h[(k + 3, k)];
+            }
+        }
+
+        if let Some((v, beta)) = householder(&[x, y]) {
+            reflect_rows(h, hi - 1, &v, beta, hi - 2..M);
+            reflect_cols(h, hi - 1, &v, beta, 0..hi + 1);
+            if let Some(q) = q.as_deref_mut() {
+                reflect_cols(q, hi - 1, &v, beta, 0..M);
+            }
+        }
+
+        for i in l + 2..=hi {
+            h[(i, i - 2)] = T::id_add();
+            if i >= l + 3 {
+                h[(i, i - 3)] = T::id_add();
+            }
+        }
+    }
+
+    Some(())
+}
+
+/// Triangularizes the converged 2x2 block at `p` if its eigenvalues are real.
+fn split<T, const M: usize>(h: &mut Matrix<T, M, M>, q: Option<&mut Matrix<T, M, M>>, p: usize)
+where
+    T: Real,
+{
+    let (a, b, c, d) = (h[(p, p)], h[(p, p + 1)], h[(p + 1, p)], h[(p + 1, p + 1)]);
+    let two = T::from_usize(2);
+    let mid = (a + d) / two;
+    let disc = (a - d) * (a - d) / (two * two) + b * c;
+    if disc < T::id_add() {
+        return;
+    }
+
+    let root = disc.sqrt();
+    let lambda = if mid < T::id_add() {
+        mid - root
+    } else {
+        mid + root
+    };
+    let (x, y) = if (b.abs() + (lambda - a).abs()) > ((lambda - d).abs() + c.abs()) {
+        (b, lambda - a)
+    } else {
+        (lambda - d, c)
+    };
+    let nrm = (x * x + y * y).sqrt();
+    if nrm == T::id_add() {
+        return;
+    }
+    let (cs, sn) = (x / nrm, y / nrm);
+
+    h.rotate_rows(p, p + 1, cs, -sn);
+    h.rotate_cols(p, p + 1, cs, -sn);
+    if let Some(q) = q {
+        q.rotate_cols(p, p + 1, cs, -sn);
+    }
+    h[(p + 1, p)] = T::id_add();
+}
+
+fn eigenvalues<T, const M: usize>(t: &Matrix<T, M, M>) -> [Complex<T>; M]
+where
+    T: Real,
+{
+    let mut values = [Complex::new(T::id_add(), T::id_add()); M];
+
+    let mut i = 0;
+    while i < M {
+        if i + 1 < M && t[(i + 1, i)] != T::id_add() {
+            let (a, b, c, d) = (t[(i, i)], t[(i, i + 1)], t[(i + 1, i)], t[(i + 1, i + 1)]);
+            let two = T::from_usize(2);
+            let mid = (a + d) / two;
+            let im = (-((a - d) * (a - d) / (two * two) + b * c)).sqrt();
+
+            values[i] = Complex::new(mid, im);
+            values[i + 1] = Complex::new(mid, -im);
+            i += 2;
+        } else {
+            values[i] = Complex::new(t[(i, i)], T::id_add());
+            i += 1;
+        }
+    }
+
+    values
+}
+
+/// Computes `v` and `beta` such that `(I - beta * v * vᵀ) * x` is a multiple of
+/// the first unit vector, or `None` if `x` is already zero.
+fn householder<T>(x: &[T]) -> Option<(Vec<T>, T)>
+where
+    T: Real,
+{
+    let nrm = x.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+    if nrm == T::id_add() {
+        return None;
+    }
+
+    let alpha = if x[0] < T::id_add() { nrm } else { -nrm };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+
+    let vtv = v.iter().fold(T::id_add(), |sum, &x| sum + x * x);
+    if vtv == T::id_add() {
+        return None;
+    }
+
+    Some((v, T::from_usize(2) / vtv))
+}
+
+fn reflect_rows<T, const M: usize, const N: usize>(
+    a: &mut Matrix<T, M, N>,
+    k: usize,
+    v: &[T],
+    beta: T,
+    cols: Range<usize>,
+) where
+    T: Real,
+{
+    for j in cols {
+        let s = beta * (0..v.len()).fold(T::id_add(), |s, i| s + v[i] * a[(k + i, j)]);
+        for (i, &v) in v.iter().enumerate() {
+            a[(k + i, j)] = a[(k + i, j)] - s * v;
+        }
+    }
+}
+
+fn reflect_cols<T, const M: usize, const N: usize>(
+    a: &mut Matrix<T, M, N>,
+    k: usize,
+    v: &[T],
+    beta: T,
+    rows: Range<usize>,
+) where
+    T: Real,
+{
+    for i in rows {
+        let s = beta * (0..v.len()).fold(T::id_add(), |s, j| s + v[j] * a[(i, k + j)]);
+        for (j, &v) in v.iter().enumerate() {
+            a[(i, k + j)] = a[(i, k + j)] - s * v;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx;
+
+    fn transpose<const M: usize>(mat: &Matrix<f64, M, M>) -> Matrix<f64, M, M> {
+        mat.clone().transpose()
+    }
+
+    #[test]
+    fn hessenberg() {
+        let mat = Matrix::from_rows([
+            [4.0, -2.0, 1.0, 3.0],
+            [3.0, 1.0, 0.0, -1.0],
+            [2.0, 5.0, -3.0, 1.0],
+            [1.0, 0.0, 2.0, 6.0],
+        ]);
+        let hess = mat.clone().hessenberg();
+
+        for i in 2..4 {
+            for j in 0..i - 1 {
+                assert_eq!(0.0, hess.h()[(i, j)]);
+            }
+        }
+        assert_approx(
+            Matrix::id_mul(),
+            transpose(hess.q()) * hess.q().clone(),
+            1e-10,
+        );
+        assert_approx(
+            mat,
+            hess.q().clone() * hess.h().clone() * transpose(hess.q()),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn schur() {
+        let mat = Matrix::from_rows([
+            [4.0, -2.0, 1.0, 3.0],
+            [3.0, 1.0, 0.0, -1.0],
+            [2.0, 5.0, -3.0, 1.0],
+            [1.0, 0.0, 2.0, 6.0],
+        ]);
+        let schur = mat.clone().schur().unwrap();
+        let t = schur.t();
+
+        for i in 2..4 {
+            for j in 0..i - 1 {
+                assert_eq!(0.0, t[(i, j)]);
+            }
+        }
+        for i in 0..3 {
+            assert!(t[(i + 1, i)] == 0.0 || i == 0 || t[(i, i - 1)] == 0.0);
+        }
+        assert_approx(
+            Matrix::id_mul(),
+            transpose(schur.q()) * schur.q().clone(),
+            1e-10,
+        );
+        assert_approx(
+            mat.clone(),
+            schur.q().clone() * t.clone() * transpose(schur.q()),
+            1e-10,
+        );
+
+        let values = schur.eigenvalues();
+        let sum = values
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |sum, &x| sum + x);
+        let prod = values
+            .iter()
+            .fold(Complex::new(1.0, 0.0), |prod, &x| prod * x);
+        assert!((sum.re - 8.0).abs() < 1e-10 && sum.im.abs() < 1e-10);
+        assert!((prod.re - mat.det()).abs() < 1e-8 && prod.im.abs() < 1e-8);
+    }
+
+    #[test]
+    fn real_eigenvalues() {
+        // Companion matrix of (x - 1)(x - 2)(x - 3).
+        let mat = Matrix::from_rows([[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let mut values = mat.eigenvalues().unwrap();
+        values.sort_by(|x, y| x.re.partial_cmp(&y.re).unwrap());
+
+        for (expected, actual) in [1.0_f64, 2.0, 3.0].iter().zip(values) {
+            assert!((expected - actual.re).abs() < 1e-10);
+            assert_eq!(0.0, actual.im);
+        }
+    }
+
+    #[test]
+    fn complex_eigenvalues() {
+        let mat = Matrix::from_rows([[0.0, -1.0], [1.0, 0.0]]);
+        assert_eq!(
+            [Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)],
+            mat.eigenvalues().unwrap()
+        );
+
+        let mat = Matrix::from_rows([[1.0, 2.0, 0.0], [-2.0, 1.0, 0.0], [0.0, 0.0, 3.0]]);
+        let values = mat.eigenvalues().unwrap();
+        assert!(values.contains(&Complex::new(1.0, 2.0)));
+        assert!(values.contains(&Complex::new(1.0, -2.0)));
+        assert!(values.contains(&Complex::new(3.0, 0.0)));
+    }
+}
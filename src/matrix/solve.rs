@@ -0,0 +1,59 @@
+use super::Matrix;
+use crate::scalar::Real;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    Singular,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Singular => write!(f, "matrix is singular"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn solve<const K: usize>(&self, b: Matrix<T, M, K>) -> Result<Matrix<T, M, K>, SolveError> {
+        self.clone().lu().solve(&b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve() {
+        let mat = Matrix::from_rows([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+        let x = mat
+            .solve(Matrix::from_rows([[8.0, 1.0], [-11.0, -1.0], [-3.0, 0.0]]))
+            .unwrap();
+
+        assert_eq!(
+            Matrix::from_rows([[2.0, 1.0], [3.0, 0.0], [-1.0, 1.0]]),
+            x.into_map(|_, _, x| (1000.0_f64 * x).round() / 1000.0)
+        );
+    }
+
+    #[test]
+    fn solve_singular() {
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        assert_eq!(
+            Err(SolveError::Singular),
+            mat.solve(Matrix::from_rows([[1.0], [2.0], [3.0]]))
+        );
+        assert_eq!("matrix is singular", SolveError::Singular.to_string());
+    }
+}
@@ -0,0 +1,303 @@
+use super::{cholesky::Cholesky, Matrix};
+use crate::{
+    scalar::Real,
+    solvers::{Convergence, SolverConfig},
+};
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    /// Whether every diagonal entry outweighs the rest of its row, which
+    /// guarantees that all the stationary methods converge.
+    pub fn is_diagonally_dominant(&self) -> bool {
+        (0..M).all(|i| {
+            let off = (0..M)
+                .filter(|&j| j != i)
+                .fold(T::id_add(), |sum, j| sum + self[(i, j)].abs());
+            self[(i, i)].abs() > off
+        })
+    }
+
+    /// Whether SOR with factor `omega` is guaranteed to converge. Symmetric
+    /// positive-definite matrices converge for any `omega` in (0, 2), while
+    /// diagonal dominance only covers under-relaxation.
+    ///
+    /// Checking for positive definiteness takes a Cholesky decomposition, so
+    /// callers iterating repeatedly on the same matrix should check once.
+    pub fn sor_converges(&self, omega: T) -> bool {
+        let two = T::id_mul() + T::id_mul();
+        omega > T::id_add()
+            && omega < two
+            && ((self.is_diagonally_dominant() && omega <= T::id_mul())
+                || Cholesky::new(self.clone()).is_some())
+    }
+
+    /// Solves `A * x = b` by Jacobi iteration, starting from the guess in `x`.
+    /// Convergence is only guaranteed if [`is_diagonally_dominant`](Self::is_diagonally_dominant)
+    /// holds, which the result reports in [`guaranteed`](Convergence::guaranteed).
+    ///
+    /// `callback` receives the iteration count, the current iterate and its
+    /// relative residual norm after every sweep.
+    pub fn jacobi<F>(
+        &self,
+        b: &[T; M],
+        x: &mut [T; M],
+        config: &SolverConfig<T>,
+        callback: F,
+    ) -> Convergence<T>
+    where
+        F: FnMut(usize, &[T; M], T),
+    {
+        let guaranteed = self.is_diagonally_dominant();
+        self.iterate(b, x, config, guaranteed, callback, |a, b, x| {
+            let prev = *x;
+            for i in 0..M {
+                let sum = (0..M)
+                    .filter(|&j| j != i)
+                    .fold(b[i], |sum, j| sum - a[(i, j)] * prev[j]);
+                x[i] = sum / a[(i, i)];
+            }
+        })
+    }
+
+    /// Solves `A * x = b` by Gauss–Seidel iteration, starting from the guess in `x`.
+    /// Convergence is only guaranteed if [`sor_converges`](Self::sor_converges) holds for `1`,
+    /// which the result reports in [`guaranteed`](Convergence::guaranteed).
+    ///
+    /// `callback` receives the iteration count, the current iterate and its
+    /// relative residual norm after every sweep.
+    pub fn gauss_seidel<F>(
+        &self,
+        b: &[T; M],
+        x: &mut [T; M],
+        config: &SolverConfig<T>,
+        callback: F,
+    ) -> Convergence<T>
+    where
+        F: FnMut(usize, &[T; M], T),
+    {
+        self.sor(b, x, T::id_mul(), config, callback)
+    }
+
+    /// Solves `A * x = b` by successive over-relaxation with factor `omega`,
+    /// starting from the guess in `x`. Convergence is only guaranteed if
+    /// [`sor_converges`](Self::sor_converges) holds for `omega`, which the
+    /// result reports in [`guaranteed`](Convergence::guaranteed).
+    ///
+    /// `callback` receives the iteration count, the current iterate and its
+    /// relative residual norm after every sweep.
+    pub fn sor<F>(
+        &self,
+        b: &[T; M],
+        x: &mut [T; M],
+        omega: T,
+        config: &SolverConfig<T>,
+        callback: F,
+    ) -> Convergence<T>
+    where
+        F: FnMut(usize, &[T; M], T),
+    {
+        let two = T::id_mul() + T::id_mul();
+        assert!(
+            omega > T::id_add() && omega < two,
+            "cannot relax with a factor outside of (0, 2)"
+        );
+
+        let guaranteed = self.sor_converges(omega);
+        self.iterate(b, x, config, guaranteed, callback, |a, b, x| {
+            for i in 0..M {
+                let sum = (0..M)
+                    .filter(|&j| j != i)
+                    .fold(b[i], |sum, j| sum - a[(i, j)] * x[j]);
+                x[i] = (T::id_mul() - omega) * x[i] + omega * sum / a[(i, i)];
+            }
+        })
+    }
+
+    fn iterate<F, S>(
+        &self,
+        b: &[T; M],
+        x: &mut [T; M],
+        config: &SolverConfig<T>,
+        guaranteed: bool,
+        mut callback: F,
+        mut sweep: S,
+    ) -> Convergence<T>
+    where
+        F: FnMut(usize, &[T; M], T),
+        S: FnMut(&Self, &[T; M], &mut [T; M]),
+    {
+        assert!(
+            (0..M).all(|i| self[(i, i)] != T::id_add()),
+            "cannot iterate on a matrix with a zero on its diagonal"
+        );
+
+        let norm = |x: &[T; M]| x.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+        let bnorm = match norm(b) {
+            bnorm if bnorm == T::id_add() => T::id_mul(),
+            bnorm => bnorm,
+        };
+        let residual = |x: &[T; M]| {
+            let r = std::array::from_fn(|i| (0..M).fold(b[i], |r, j| r - self[(i, j)] * x[j]));
+            norm(&r) / bnorm
+        };
+
+        let mut history = vec![residual(x)];
+        let mut iterations = 0;
+        while history[iterations] > config.tol && iterations < config.max_iter {
+            sweep(self, b, x);
+            iterations += 1;
+            history.push(residual(x));
+            callback(iterations, x, history[iterations]);
+        }
+
+        Convergence {
+            converged: history[iterations] <= config.tol,
+            iterations,
+            history,
+            guaranteed: Some(guaranteed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn system() -> (Matrix<f64, 3, 3>, [f64; 3]) {
+        (
+            Matrix::from_rows([[4.0, -1.0, 1.0], [-1.0, 5.0, 2.0], [1.0, 2.0, 6.0]]),
+            [4.0, 6.0, 9.0],
+        )
+    }
+
+    fn assert_solution(x: [f64; 3]) {
+        x.iter()
+            .for_each(|&x| assert!((1.0 - x).abs() < 1e-9, "{}", x));
+    }
+
+    #[test]
+    fn jacobi() {
+        let (a, b) = system();
+        let mut x = [0.0; 3];
+        let result = a.jacobi(&b, &mut x, &SolverConfig::default(), |_, _, _| {});
+
+        assert!(result.converged);
+        assert_eq!(Some(true), result.guaranteed);
+        assert_solution(x);
+    }
+
+    #[test]
+    fn gauss_seidel() {
+        let (a, b) = system();
+        let config = SolverConfig::default();
+
+        let mut x = [0.0; 3];
+        let jacobi = a.jacobi(&b, &mut x, &config, |_, _, _| {});
+        let mut x = [0.0; 3];
+        let result = a.gauss_seidel(&b, &mut x, &config, |_, _, _| {});
+
+        assert!(result.converged);
+        assert!(result.iterations < jacobi.iterations);
+        assert_solution(x);
+    }
+
+    #[test]
+    fn sor() {
+        let (a, b) = system();
+        let mut x = [0.0; 3];
+        let result = a.sor(&b, &mut x, 1.1, &SolverConfig::default(), |_, _, _| {});
+
+        assert!(result.converged);
+        assert_solution(x);
+    }
+
+    #[test]
+    fn callback() {
+        let (a, b) = system();
+        let mut calls = Vec::new();
+        let mut x = [0.0; 3];
+        let result = a.gauss_seidel(&b, &mut x, &SolverConfig::new(1e-12, 5), |k, _, r| {
+            calls.push((k, r))
+        });
+
+        assert!(!result.converged);
+        assert_eq!(5, result.iterations);
+        assert_eq!(
+            (1..=5)
+                .zip(result.history[1..].iter().copied())
+                .collect::<Vec<_>>(),
+            calls
+        );
+    }
+
+    #[test]
+    fn diagonally_dominant() {
+        assert!(system().0.is_diagonally_dominant());
+        assert!(!Matrix::from_rows([[1.0, 2.0], [0.5, 1.0]]).is_diagonally_dominant());
+    }
+
+    #[test]
+    fn sor_converges() {
+        let (a, _) = system();
+        assert!(a.sor_converges(1.5));
+        assert!(!a.sor_converges(2.0));
+
+        // Diagonally dominant but not symmetric, so only under-relaxation is covered.
+        let a = Matrix::from_rows([[4.0, 1.0], [-2.0, 5.0]]);
+        assert!(a.sor_converges(0.8));
+        assert!(!a.sor_converges(1.5));
+    }
+
+    #[test]
+    fn not_guaranteed() {
+        // Neither diagonally dominant nor positive definite.
+        let a = Matrix::from_rows([[1.0, 0.5, 0.0], [0.5, 1.0, 0.6], [0.0, 0.6, 0.3]]);
+        let mut x = [0.0; 3];
+        let result = a.jacobi(
+            &[1.0, 1.0, 1.0],
+            &mut x,
+            &SolverConfig::default(),
+            |_, _, _| {},
+        );
+
+        assert_eq!(Some(false), result.guaranteed);
+        assert_eq!(
+            Some(false),
+            a.sor(
+                &[1.0, 1.0, 1.0],
+                &mut [0.0; 3],
+                1.5,
+                &SolverConfig::default(),
+                |_, _, _| {}
+            )
+            .guaranteed
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot relax with a factor outside of (0, 2)")]
+    fn sor_factor() {
+        let (a, b) = system();
+        a.sor(
+            &b,
+            &mut [0.0; 3],
+            2.0,
+            &SolverConfig::default(),
+            |_, _, _| {},
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot iterate on a matrix with a zero on its diagonal")]
+    fn zero_diagonal() {
+        let a = Matrix::from_rows([[0.0, 1.0], [1.0, 0.0]]);
+        a.jacobi(
+            &[1.0, 1.0],
+            &mut [0.0; 2],
+            &SolverConfig::default(),
+            |_, _, _| {},
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use super::{gemm, Matrix};
+use crate::{dmatrix::DMatrix, scalar::Scalar};
+
+/// Default size below which Strassen's recursion hands off to the ordinary
+/// product.
+pub const STRASSEN_CUTOFF: usize = 128;
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Scalar + 'static,
+{
+    /// Multiplies by Strassen's algorithm, recursing until blocks are at most
+    /// [`STRASSEN_CUTOFF`] wide.
+    ///
+    /// See [`mul_strassen_with_cutoff`](Self::mul_strassen_with_cutoff) for
+    /// how its error compares to the ordinary product.
+    pub fn mul_strassen(&self, rhs: &Self) -> Self {
+        self.mul_strassen_with_cutoff(rhs, STRASSEN_CUTOFF)
+    }
+
+    /// Multiplies by Strassen's algorithm, recursing until blocks are at most
+    /// `cutoff` wide and multiplying those the ordinary way.
+    ///
+    /// Integer products are exact. For floats with unit roundoff `u`, the
+    /// ordinary product is bounded componentwise by
+    /// `|C - Ĉ| <= n u |A| |B|`, whereas Strassen's is only bounded normwise by
+    /// `max|C - Ĉ| <= ((n / n0)^log2(12) (n0² + 5 n0) - 5 n) u max|A| max|B|`
+    /// for blocks of size `n0`. Entries much smaller than the norms of the
+    /// factors may therefore lose all relative accuracy, and a larger cutoff
+    /// trades speed for a smaller constant.
+    pub fn mul_strassen_with_cutoff(&self, rhs: &Self, cutoff: usize) -> Self {
+        let c = strassen(M, self.0.as_flattened(), rhs.0.as_flattened(), cutoff);
+        self.map(|i, j, _| c[i * M + j])
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Scalar + 'static,
+{
+    /// Multiplies two square matrices by Strassen's algorithm, recursing until
+    /// blocks are at most [`STRASSEN_CUTOFF`] wide.
+    pub fn mul_strassen(&self, rhs: &Self) -> Self {
+        self.mul_strassen_with_cutoff(rhs, STRASSEN_CUTOFF)
+    }
+
+    /// Multiplies two square matrices by Strassen's algorithm, recursing until
+    /// blocks are at most `cutoff` wide, with the error bounds of
+    /// [`Matrix::mul_strassen_with_cutoff`].
+    pub fn mul_strassen_with_cutoff(&self, rhs: &Self, cutoff: usize) -> Self {
+        assert!(
+            self.nrows() == self.ncols() && self.shape() == rhs.shape(),
+            "cannot multiply a {}x{} matrix by a {}x{} matrix with Strassen's algorithm",
+            self.nrows(),
+            self.ncols(),
+            rhs.nrows(),
+            rhs.ncols()
+        );
+
+        let n = self.nrows();
+        DMatrix::from_vec(n, n, strassen(n, self.as_slice(), rhs.as_slice(), cutoff))
+    }
+}
+
+/// Multiplies two row-major `n x n` matrices after padding them with zeros to
+/// a size that halves evenly down to at most `cutoff`.
+fn strassen<T>(n: usize, a: &[T], b: &[T], cutoff: usize) -> Vec<T>
+where
+    T: Scalar + 'static,
+{
+    assert!(cutoff > 0, "cannot recurse down to blocks of size zero");
+
+    let (mut base, mut levels) = (n, 0);
+    while base > cutoff {
+        base = base.div_ceil(2);
+        levels += 1;
+    }
+    let size = base << levels;
+
+    if size == n {
+        return recurse(n, a, b, cutoff);
+    }
+
+    let pad = |x: &[T]| {
+        let mut padded = vec![T::id_add(); size * size];
+        for (i, row) in x.chunks_exact(n.max(1)).enumerate() {
+            padded[i * size..i * size + n].copy_from_slice(row);
+        }
+        padded
+    };
+    let c = recurse(size, &pad(a), &pad(b), cutoff);
+
+    c.chunks_exact(size)
+        .take(n)
+        .flat_map(|row| row[..n].iter().copied())
+        .collect()
+}
+
+fn recurse<T>(n: usize, a: &[T], b: &[T], cutoff: usize) -> Vec<T>
+where
+    T: Scalar + 'static,
+{
+    if n <= cutoff {
+        let mut c = vec![T::id_add(); n * n];
+        if !gemm::try_gemm(n, n, n, a, b, &mut c) {
+            for i in 0..n {
+                for k in 0..n {
+                    let aik = a[i * n + k];
+                    for j in 0..n {
+                        c[i * n + j] = c[i * n + j] + aik * b[k * n + j];
+                    }
+                }
+            }
+        }
+        return c;
+    }
+
+    let h = n / 2;
+    let [a11, a12, a21, a22] = split(n, a);
+    let [b11, b12, b21, b22] = split(n, b);
+
+    let m1 = recurse(h, &add(&a11, &a22), &add(&b11, &b22), cutoff);
+    let m2 = recurse(h, &add(&a21, &a22), &b11, cutoff);
+    let m3 = recurse(h, &a11, &sub(&b12, &b22), cutoff);
+    let m4 = recurse(h, &a22, &sub(&b21, &b11), cutoff);
+    let m5 = recurse(h, &add(&a11, &a12), &b22, cutoff);
+    let m6 = recurse(h, &sub(&a21, &a11), &add(&b11, &b12), cutoff);
+    let m7 = recurse(h, &sub(&a12, &a22), &add(&b21, &b22), cutoff);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = vec![T::id_add(); n * n];
+    for i in 0..h {
+        let (top, bottom) = (i * n, (i + h) * n);
+        c[top..top + h].copy_from_slice(&c11[i * h..(i + 1) * h]);
+        c[top + h..top + n].copy_from_slice(&c12[i * h..(i + 1) * h]);
+        c[bottom..bottom + h].copy_from_slice(&c21[i * h..(i + 1) * h]);
+        c[bottom + h..bottom + n].copy_from_slice(&c22[i * h..(i + 1) * h]);
+    }
+
+    c
+}
+
+/// Splits an `n x n` matrix into its four `n / 2 x n / 2` quadrants.
+fn split<T>(n: usize, x: &[T]) -> [Vec<T>; 4]
+where
+    T: Scalar + 'static,
+{
+    let h = n / 2;
+    let quadrant = |i0: usize, j0: usize| {
+        (i0..i0 + h)
+            .flat_map(|i| x[i * n + j0..i * n + j0 + h].iter().copied())
+            .collect()
+    };
+
+    [
+        quadrant(0, 0),
+        quadrant(0, h),
+        quadrant(h, 0),
+        quadrant(h, h),
+    ]
+}
+
+fn add<T>(x: &[T], y: &[T]) -> Vec<T>
+where
+    T: Scalar + 'static,
+{
+    x.iter().zip(y).map(|(&x, &y)| x + y).collect()
+}
+
+fn sub<T>(x: &[T], y: &[T]) -> Vec<T>
+where
+    T: Scalar + 'static,
+{
+    x.iter().zip(y).map(|(&x, &y)| x - y).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::sample;
+
+    #[test]
+    fn exact() {
+        let (a, b) = (sample::<i64, 33, 33>(1), sample::<i64, 33, 33>(2));
+        let expected = a.clone() * b.clone();
+
+        for cutoff in [1, 4, 16, 33] {
+            assert_eq!(expected, a.mul_strassen_with_cutoff(&b, cutoff));
+        }
+        assert_eq!(expected, a.mul_strassen(&b));
+    }
+
+    #[test]
+    fn float() {
+        let a = sample::<i64, 64, 64>(3).map(|_, _, &x| x as f64 / 3.0);
+        let b = sample::<i64, 64, 64>(4).map(|_, _, &x| x as f64 / 7.0);
+        let expected = a.clone() * b.clone();
+
+        a.mul_strassen_with_cutoff(&b, 8)
+            .iter()
+            .zip(expected.iter())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-10, "{} != {}", x, y));
+    }
+
+    #[test]
+    fn dmatrix() {
+        let a = DMatrix::from(sample::<i64, 50, 50>(5));
+        let b = DMatrix::from(sample::<i64, 50, 50>(6));
+
+        assert_eq!(a.clone() * b.clone(), a.mul_strassen_with_cutoff(&b, 8));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot multiply a 2x3 matrix by a 2x3 matrix with Strassen's algorithm"
+    )]
+    fn dmatrix_non_square() {
+        let a = DMatrix::from_vec(2, 3, vec![0; 6]);
+        let _ = a.mul_strassen(&a);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot recurse down to blocks of size zero")]
+    fn zero_cutoff() {
+        let a = sample::<i64, 4, 4>(0);
+        let _ = a.mul_strassen_with_cutoff(&a, 0);
+    }
+}
@@ -0,0 +1,243 @@
+use super::Matrix;
+use crate::{dmatrix::DMatrix, scalar::Real};
+
+/// Algorithm used to orthonormalize the columns of a matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orthonormalization {
+    /// Modified Gram–Schmidt, which is cheap but loses orthogonality as the
+    /// columns approach linear dependence.
+    ModifiedGramSchmidt,
+    /// Householder reflections with column pivoting, which stay orthogonal to
+    /// working precision.
+    #[default]
+    Householder,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Real,
+{
+    /// Returns an orthonormal basis of the vectors `x` with `A * x = 0` as columns.
+    pub fn null_space(&self) -> DMatrix<T> {
+        let svd = self.clone().svd();
+        let r = svd.rank(svd.default_tol());
+        DMatrix::from_fn(N, N - r, |i, j| svd.vt()[(r + j, i)])
+    }
+
+    /// Returns an orthonormal basis of the span of the columns as columns.
+    pub fn column_space(&self) -> DMatrix<T> {
+        let svd = self.clone().svd();
+        let r = svd.rank(svd.default_tol());
+        DMatrix::from_fn(M, r, |i, j| svd.u()[(i, j)])
+    }
+
+    /// Returns an orthonormal basis of the span of the rows as columns.
+    pub fn row_space(&self) -> DMatrix<T> {
+        let svd = self.clone().svd();
+        let r = svd.rank(svd.default_tol());
+        DMatrix::from_fn(N, r, |i, j| svd.vt()[(j, i)])
+    }
+
+    /// Returns an orthonormal basis of the span of the columns as columns,
+    /// dropping any column that is linearly dependent on the others.
+    pub fn orthonormalize(&self, method: Orthonormalization) -> DMatrix<T> {
+        let cols = match method {
+            Orthonormalization::ModifiedGramSchmidt => self.gram_schmidt(),
+            Orthonormalization::Householder => self.householder(),
+        };
+        DMatrix::from_fn(M, cols.len(), |i, j| cols[j][i])
+    }
+
+    /// Tolerance below which a column norm counts as zero.
+    fn col_tol(&self) -> T {
+        let max = (0..N).fold(T::id_add(), |max, j| {
+            let norm = (0..M)
+                .fold(T::id_add(), |sum, i| sum + self[(i, j)] * self[(i, j)])
+                .sqrt();
+            if norm > max {
+                norm
+            } else {
+                max
+            }
+        });
+
+        T::EPSILON * T::from_usize(M.max(N)) * max
+    }
+
+    fn gram_schmidt(&self) -> Vec<[T; M]> {
+        let tol = self.col_tol();
+        let mut basis: Vec<[T; M]> = Vec::with_capacity(M.min(N));
+
+        for j in 0..N {
+            let mut v: [T; M] = std::array::from_fn(|i| self[(i, j)]);
+            for q in &basis {
+                let dot = (0..M).fold(T::id_add(), |dot, i| dot + q[i] * v[i]);
+                for i in 0..M {
+                    v[i] = v[i] - dot * q[i];
+                }
+            }
+
+            let norm = v.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+            if norm > tol {
+                basis.push(v.map(|x| x / norm));
+            }
+        }
+
+        basis
+    }
+
+    fn householder(&self) -> Vec<[T; M]> {
+        let tol = self.col_tol();
+        let two = T::id_mul() + T::id_mul();
+        let mut a = self.clone();
+        let mut reflectors: Vec<(usize, Vec<T>)> = Vec::with_capacity(M.min(N));
+
+        for k in 0..M.min(N) {
+            let norm = |a: &Self, j: usize| {
+                (k..M)
+                    .fold(T::id_add(), |sum, i| sum + a[(i, j)] * a[(i, j)])
+                    .sqrt()
+            };
+            let p = (k + 1..N).fold(k, |p, j| if norm(&a, j) > norm(&a, p) { j } else { p });
+            let alpha = norm(&a, p);
+            if alpha <= tol {
+                break;
+            }
+            for i in 0..M {
+                a.0[i].swap(k, p);
+            }
+
+            let alpha = if a[(k, k)] > T::id_add() {
+                -alpha
+            } else {
+                alpha
+            };
+            let mut v: Vec<T> = (k..M).map(|i| a[(i, k)]).collect();
+            v[0] = v[0] - alpha;
+            let vnorm = v.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+            v.iter_mut().for_each(|x| *x = *x / vnorm);
+
+            for j in k..N {
+                let dot = (k..M).fold(T::id_add(), |dot, i| dot + v[i - k] * a[(i, j)]);
+                for i in k..M {
+                    a[(i, j)] = a[(i, j)] - two * dot * v[i - k];
+                }
+            }
+            reflectors.push((k, v));
+        }
+
+        (0..reflectors.len())
+            .map(|j| {
+                let mut q: [T; M] =
+                    std::array::from_fn(|i| if i == j { T::id_mul() } else { T::id_add() });
+                for (k, v) in reflectors.iter().rev() {
+                    let dot = (*k..M).fold(T::id_add(), |dot, i| dot + v[i - k] * q[i]);
+                    for i in *k..M {
+                        q[i] = q[i] - two * dot * v[i - k];
+                    }
+                }
+                q
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_orthonormal(q: &DMatrix<f64>) {
+        let qtq = q.clone().transpose() * q.clone();
+        for i in 0..q.ncols() {
+            for j in 0..q.ncols() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((expected - qtq[(i, j)]).abs() < 1e-12, "{}", qtq);
+            }
+        }
+    }
+
+    /// Asserts that every column of `a` lies in the span of the orthonormal columns of `q`.
+    fn assert_spans(q: &DMatrix<f64>, a: &DMatrix<f64>) {
+        let projected = q.clone() * (q.clone().transpose() * a.clone());
+        projected
+            .iter()
+            .zip(a.iter())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-10, "{} != {}", x, y));
+    }
+
+    fn deficient() -> Matrix<f64, 3, 4> {
+        Matrix::from_rows([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [1.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    #[test]
+    fn null_space() {
+        let mat = deficient();
+        let null = mat.null_space();
+
+        assert_eq!((4, 2), null.shape());
+        assert_orthonormal(&null);
+        (DMatrix::from(mat) * null)
+            .iter()
+            .for_each(|x| assert!(x.abs() < 1e-12));
+
+        assert_eq!(
+            (2, 0),
+            Matrix::from_rows([[1.0, 2.0], [3.0, 4.0]])
+                .null_space()
+                .shape()
+        );
+    }
+
+    #[test]
+    fn column_space() {
+        let mat = deficient();
+        let col = mat.column_space();
+
+        assert_eq!((3, 2), col.shape());
+        assert_orthonormal(&col);
+        assert_spans(&col, &DMatrix::from(mat));
+    }
+
+    #[test]
+    fn row_space() {
+        let mat = deficient();
+        let row = mat.row_space();
+
+        assert_eq!((4, 2), row.shape());
+        assert_orthonormal(&row);
+        assert_spans(&row, &DMatrix::from(mat.transpose()));
+    }
+
+    #[test]
+    fn orthonormalize() {
+        let mat = deficient();
+        for method in [
+            Orthonormalization::ModifiedGramSchmidt,
+            Orthonormalization::Householder,
+        ] {
+            let q = mat.orthonormalize(method);
+
+            assert_eq!((3, 2), q.shape());
+            assert_orthonormal(&q);
+            assert_spans(&q, &DMatrix::from(mat.clone()));
+        }
+    }
+
+    #[test]
+    fn orthonormalize_full() {
+        let mat = Matrix::from_rows([[1.0, 1.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        let mgs = mat.orthonormalize(Orthonormalization::ModifiedGramSchmidt);
+
+        assert_eq!((4, 2), mgs.shape());
+        assert_orthonormal(&mgs);
+        assert_spans(&mgs, &DMatrix::from(mat.clone()));
+
+        let householder = mat.orthonormalize(Orthonormalization::Householder);
+        assert_orthonormal(&householder);
+        assert_spans(&householder, &DMatrix::from(mat));
+    }
+}
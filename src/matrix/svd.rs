@@ -0,0 +1,370 @@
+use super::Matrix;
+use crate::{
+    identity::{IdAdd, IdMul},
+    scalar::Real,
+};
+use std::{array, cmp::Ordering};
+
+const MAX_SWEEPS: usize = 64;
+
+/// Singular value decomposition by one-sided Jacobi rotations, `A = U * Σ * Vᵀ`.
+#[derive(Debug, Clone)]
+pub struct Svd<T, const M: usize, const N: usize> {
+    u: Matrix<T, M, M>,
+    s: [T; N],
+    vt: Matrix<T, N, N>,
+}
+
+impl<T, const M: usize, const N: usize> Svd<T, M, N>
+where
+    T: Real,
+{
+    pub fn new(a: Matrix<T, M, N>) -> Self {
+        if M >= N {
+            let (u, s, v) = jacobi(a);
+            Self {
+                u,
+                s,
+                vt: v.transpose(),
+            }
+        } else {
+            let (v, s, u) = jacobi(a.transpose());
+            Self {
+                u,
+                s: array::from_fn(|i| if i < M { s[i] } else { T::id_add() }),
+                vt: v.transpose(),
+            }
+        }
+    }
+
+    pub fn u(&self) -> &Matrix<T, M, M> {
+        &self.u
+    }
+
+    /// Returns the `min(M, N)` singular values in descending order.
+    pub fn singular_values(&self) -> &[T] {
+        &self.s[..M.min(N)]
+    }
+
+    pub fn sigma(&self) -> Matrix<T, M, N> {
+        Matrix::<T, M, N>::id_add().into_map(|i, j, x| if i == j { self.s[i] } else { x })
+    }
+
+    pub fn vt(&self) -> &Matrix<T, N, N> {
+        &self.vt
+    }
+
+    /// Returns the thin factors, where `K` must equal `min(M, N)`.
+    pub fn thin<const K: usize>(&self) -> (Matrix<T, M, K>, [T; K], Matrix<T, K, N>) {
+        assert_eq!(
+            M.min(N),
+            K,
+            "thin factors of a {}x{} matrix have {} singular values",
+            M,
+            N,
+            M.min(N)
+        );
+
+        (
+            Matrix::from_rows(array::from_fn(|i| array::from_fn(|j| self.u[(i, j)]))),
+            array::from_fn(|i| self.s[i]),
+            Matrix::from_rows(array::from_fn(|i| self.vt.0[i])),
+        )
+    }
+
+    /// Returns the tolerance below which singular values are indistinguishable from zero.
+    pub fn default_tol(&self) -> T {
+        T::EPSILON * T::from_usize(M.max(N)) * self.s.first().copied().unwrap_or(T::id_add())
+    }
+
+    /// Counts the singular values greater than `tol`.
+    pub fn rank(&self, tol: T) -> usize {
+        self.singular_values().iter().filter(|&&x| x > tol).count()
+    }
+
+    /// Returns the Moore–Penrose pseudo-inverse, inverting only the singular
+    /// values greater than `tol`.
+    pub fn pinv(&self, tol: T) -> Matrix<T, N, M> {
+        let r = self.rank(tol);
+        Matrix::<T, N, M>::id_add().into_map(|i, j, x| {
+            (0..r).fold(x, |x, k| x + self.vt[(k, i)] * self.u[(j, k)] / self.s[k])
+        })
+    }
+
+    /// Returns the minimum-norm least-squares solution of `A * X = B`, treating
+    /// the singular values not greater than `tol` as zero.
+    pub fn solve<const K: usize>(&self, b: &Matrix<T, M, K>, tol: T) -> Matrix<T, N, K> {
+        let r = self.rank(tol);
+        let ub: [[T; K]; N] = array::from_fn(|k| {
+            array::from_fn(|c| {
+                if k < r {
+                    (0..M).fold(T::id_add(), |sum, i| sum + self.u[(i, k)] * b[(i, c)]) / self.s[k]
+                } else {
+                    T::id_add()
+                }
+            })
+        });
+
+        Matrix::<T, N, K>::id_add()
+            .into_map(|i, c, x| (0..r).fold(x, |x, k| x + self.vt[(k, i)] * ub[k][c]))
+    }
+
+    /// Reconstructs `A` from its `k` largest singular values only.
+    pub fn low_rank(&self, k: usize) -> Matrix<T, M, N> {
+        Matrix::<T, M, N>::id_add().into_map(|i, j, x| {
+            (0..k.min(M.min(N))).fold(x, |x, r| x + self.u[(i, r)] * self.s[r] * self.vt[(r, j)])
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Real,
+{
+    pub fn svd(self) -> Svd<T, M, N> {
+        Svd::new(self)
+    }
+
+    pub fn pinv(&self) -> Matrix<T, N, M> {
+        let svd = self.clone().svd();
+        svd.pinv(svd.default_tol())
+    }
+
+    /// Returns the minimum-norm least-squares solution of `A * X = B` along
+    /// with the rank of `A`, treating the singular values not greater than
+    /// `tol` as zero.
+    pub fn lstsq<const K: usize>(&self, b: &Matrix<T, M, K>, tol: T) -> (Matrix<T, N, K>, usize) {
+        let svd = self.clone().svd();
+        (svd.solve(b, tol), svd.rank(tol))
+    }
+}
+
+/// Decomposes a tall matrix into a full `U`, its singular values and `V`.
+fn jacobi<T, const R: usize, const C: usize>(
+    mut w: Matrix<T, R, C>,
+) -> (Matrix<T, R, R>, [T; C], Matrix<T, C, C>)
+where
+    T: Real,
+{
+    let mut v = Matrix::<T, C, C>::id_mul();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..C {
+            for q in p + 1..C {
+                let (alpha, beta, gamma) = (0..R).fold(
+                    (T::id_add(), T::id_add(), T::id_add()),
+                    |(alpha, beta, gamma), i| {
+                        (
+                            alpha + w[(i, p)] * w[(i, p)],
+                            beta + w[(i, q)] * w[(i, q)],
+                            gamma + w[(i, p)] * w[(i, q)],
+                        )
+                    },
+                );
+                if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let two = T::id_mul() + T::id_mul();
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = (T::id_mul() / (zeta.abs() + (T::id_mul() + zeta * zeta).sqrt()))
+                    * if zeta < T::id_add() {
+                        -T::id_mul()
+                    } else {
+                        T::id_mul()
+                    };
+                let c = T::id_mul() / (T::id_mul() + t * t).sqrt();
+                let s = c * t;
+
+                w.rotate_cols(p, q, c, s);
+                v.rotate_cols(p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: [T; C] = array::from_fn(|j| {
+        (0..R)
+            .fold(T::id_add(), |sum, i| sum + w[(i, j)] * w[(i, j)])
+            .sqrt()
+    });
+    let mut order: [usize; C] = array::from_fn(|j| j);
+    order.sort_by(|&a, &b| norms[b].partial_cmp(&norms[a]).unwrap_or(Ordering::Equal));
+
+    let s = array::from_fn(|k| norms[order[k]]);
+    let v = Matrix::from_rows(array::from_fn(|i| array::from_fn(|k| v[(i, order[k])])));
+
+    let tol = T::EPSILON * T::from_usize(R) * s.first().copied().unwrap_or(T::id_add());
+    let mut u = Matrix::<T, R, R>::id_add();
+    let mut filled = [false; R];
+    for k in 0..C {
+        if s[k] > tol {
+            for i in 0..R {
+                u[(i, k)] = w[(i, order[k])] / s[k];
+            }
+            filled[k] = true;
+        }
+    }
+    complete_basis(&mut u, &mut filled);
+
+    (u, s, v)
+}
+
+/// Fills the columns of `u` not marked in `filled` with an orthonormal completion.
+fn complete_basis<T, const R: usize>(u: &mut Matrix<T, R, R>, filled: &mut [bool; R])
+where
+    T: Real,
+{
+    let half = T::id_mul() / (T::id_mul() + T::id_mul());
+
+    for k in 0..R {
+        if filled[k] {
+            continue;
+        }
+
+        for e in 0..R {
+            let mut x: [T; R] = array::from_fn(|i| if i == e { T::id_mul() } else { T::id_add() });
+            for _ in 0..2 {
+                for j in (0..R).filter(|&j| filled[j]) {
+                    let dot = (0..R).fold(T::id_add(), |dot, i| dot + u[(i, j)] * x[i]);
+                    for i in 0..R {
+                        x[i] = x[i] - dot * u[(i, j)];
+                    }
+                }
+            }
+
+            let nrm = x.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+            if nrm > half {
+                for i in 0..R {
+                    u[(i, k)] = x[i] / nrm;
+                }
+                filled[k] = true;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recip::Recip;
+    use crate::testing::assert_approx;
+
+    fn assert_orthogonal<const M: usize>(mat: &Matrix<f64, M, M>) {
+        assert_approx(
+            Matrix::id_mul(),
+            mat.clone().transpose() * mat.clone(),
+            1e-10,
+        );
+    }
+
+    #[test]
+    fn wide() {
+        let mat = Matrix::from_rows([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        let svd = mat.clone().svd();
+
+        assert_eq!(2, svd.singular_values().len());
+        assert!((5.0_f64 - svd.singular_values()[0]).abs() < 1e-12);
+        assert!((3.0_f64 - svd.singular_values()[1]).abs() < 1e-12);
+        assert_orthogonal(svd.u());
+        assert_orthogonal(svd.vt());
+        assert_approx(mat, svd.u().clone() * svd.sigma() * svd.vt().clone(), 1e-10);
+    }
+
+    #[test]
+    fn tall() {
+        let mat = Matrix::from_rows([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+        let svd = mat.clone().svd();
+
+        assert_orthogonal(svd.u());
+        assert_orthogonal(svd.vt());
+        assert_approx(
+            mat.clone(),
+            svd.u().clone() * svd.sigma() * svd.vt().clone(),
+            1e-10,
+        );
+
+        let (u, s, vt) = svd.thin::<2>();
+        let sigma = Matrix::from_rows([[s[0], 0.0], [0.0, s[1]]]);
+        assert_approx(mat, u * sigma * vt, 1e-10);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        let svd = mat.clone().svd();
+
+        assert_eq!(2, svd.rank(1e-10));
+        assert_orthogonal(svd.u());
+        assert_approx(mat.clone(), svd.low_rank(2), 1e-10);
+        assert_approx(mat, svd.u().clone() * svd.sigma() * svd.vt().clone(), 1e-10);
+    }
+
+    #[test]
+    fn pinv_tall() {
+        let mat = Matrix::from_rows([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let expected = (mat.clone().transpose() * mat.clone()).recip() * mat.clone().transpose();
+
+        assert_approx(expected, mat.pinv(), 1e-10);
+    }
+
+    #[test]
+    fn pinv_deficient() {
+        let mat = Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+        let pinv = mat.pinv();
+
+        assert_approx(mat.clone(), mat.clone() * pinv.clone() * mat.clone(), 1e-10);
+        assert_approx(pinv.clone(), pinv.clone() * mat.clone() * pinv, 1e-10);
+    }
+
+    #[test]
+    fn lstsq_overdetermined() {
+        // Fits `y = 1 + 2 x` through points that lie exactly on it.
+        let a = Matrix::from_rows([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let b = Matrix::from_rows([[1.0], [3.0], [5.0], [7.0]]);
+        let (x, rank) = a.lstsq(&b, 1e-10);
+
+        assert_eq!(2, rank);
+        assert_approx(Matrix::from_rows([[1.0], [2.0]]), x, 1e-10);
+    }
+
+    #[test]
+    fn lstsq_underdetermined() {
+        let a = Matrix::from_rows([[1.0, 1.0]]);
+        let (x, rank) = a.lstsq(&Matrix::from_rows([[2.0]]), 1e-10);
+
+        assert_eq!(1, rank);
+        assert_approx(Matrix::from_rows([[1.0], [1.0]]), x, 1e-10);
+    }
+
+    #[test]
+    fn lstsq_deficient() {
+        // The second column duplicates the first, so the minimum-norm solution
+        // splits the weight evenly between them.
+        let a = Matrix::from_rows([[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]);
+        let b = Matrix::from_rows([[2.0, 1.0], [4.0, 0.0], [6.0, 1.0]]);
+        let (x, rank) = a.lstsq(&b, 1e-10);
+
+        assert_eq!(1, rank);
+        assert_approx(
+            Matrix::from_rows([[1.0, 1.0 / 7.0], [1.0, 1.0 / 7.0]]),
+            x,
+            1e-10,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "thin factors of a 2x3 matrix have 2 singular values")]
+    fn thin_mismatch() {
+        let _ = Matrix::from_rows([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]])
+            .svd()
+            .thin::<3>();
+    }
+}
@@ -0,0 +1,267 @@
+use super::Matrix;
+use crate::{identity::IdMul, scalar::Real};
+use std::{array, cmp::Ordering};
+
+const MAX_SWEEPS: usize = 64;
+
+/// Eigendecomposition of a real symmetric matrix, `A = V * Λ * Vᵀ`.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T, const M: usize> {
+    values: [T; M],
+    vectors: Matrix<T, M, M>,
+}
+
+impl<T, const M: usize> SymmetricEigen<T, M>
+where
+    T: Real,
+{
+    pub fn new(a: Matrix<T, M, M>) -> Option<Self> {
+        if !a.is_symmetric() {
+            return None;
+        }
+
+        let (values, vectors) = if M == 3 {
+            closed_form(&a).unwrap_or_else(|| jacobi(a))
+        } else {
+            jacobi(a)
+        };
+
+        Some(Self { values, vectors })
+    }
+
+    /// Returns the eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> &[T; M] {
+        &self.values
+    }
+
+    /// Returns the orthonormal eigenvectors as columns, in the order of [`Self::eigenvalues`].
+    pub fn eigenvectors(&self) -> &Matrix<T, M, M> {
+        &self.vectors
+    }
+}
+
+impl<T, const M: usize> Matrix<T, M, M>
+where
+    T: Real,
+{
+    pub fn symmetric_eigen(self) -> Option<SymmetricEigen<T, M>> {
+        SymmetricEigen::new(self)
+    }
+}
+
+fn jacobi<T, const M: usize>(mut a: Matrix<T, M, M>) -> ([T; M], Matrix<T, M, M>)
+where
+    T: Real,
+{
+    let mut v = Matrix::<T, M, M>::id_mul();
+    let tol = T::EPSILON * a.iter().fold(T::id_add(), |sum, &x| sum + x * x).sqrt();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..M {
+            for q in p + 1..M {
+                if a[(p, q)].abs() <= tol {
+                    continue;
+                }
+                rotated = true;
+
+                let two = T::id_mul() + T::id_mul();
+                let theta = (a[(q, q)] - a[(p, p)]) / (two * a[(p, q)]);
+                let t = (T::id_mul() / (theta.abs() + (theta * theta + T::id_mul()).sqrt()))
+                    * if theta < T::id_add() {
+                        -T::id_mul()
+                    } else {
+                        T::id_mul()
+                    };
+                let c = T::id_mul() / (t * t + T::id_mul()).sqrt();
+                let s = t * c;
+
+                a.rotate_cols(p, q, c, s);
+                a.rotate_rows(p, q, c, s);
+                a[(p, q)] = T::id_add();
+                a[(q, p)] = T::id_add();
+                v.rotate_cols(p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: [usize; M] = array::from_fn(|i| i);
+    order.sort_by(|&i, &j| a[(i, i)].partial_cmp(&a[(j, j)]).unwrap_or(Ordering::Equal));
+
+    (
+        array::from_fn(|k| a[(order[k], order[k])]),
+        Matrix::from_rows(array::from_fn(|i| array::from_fn(|k| v[(i, order[k])]))),
+    )
+}
+
+/// Solves the characteristic cubic of a 3x3 matrix directly, or returns `None`
+/// when its eigenvalues are too close for the eigenvectors to be well defined.
+fn closed_form<T, const M: usize>(a: &Matrix<T, M, M>) -> Option<([T; M], Matrix<T, M, M>)>
+where
+    T: Real,
+{
+    let p1 = a[(0, 1)] * a[(0, 1)] + a[(0, 2)] * a[(0, 2)] + a[(1, 2)] * a[(1, 2)];
+    if p1 == T::id_add() {
+        return None;
+    }
+
+    let (one, two, three) = (T::id_mul(), T::from_usize(2), T::from_usize(3));
+    let q = (a[(0, 0)] + a[(1, 1)] + a[(2, 2)]) / three;
+    let p2 = (0..3).fold(two * p1, |p2, i| p2 + (a[(i, i)] - q) * (a[(i, i)] - q));
+    let p = (p2 / T::from_usize(6)).sqrt();
+
+    let b = |i: usize, j: usize| (a[(i, j)] - if i == j { q } else { T::id_add() }) / p;
+    let det = b(0, 0) * (b(1, 1) * b(2, 2) - b(1, 2) * b(2, 1))
+        - b(0, 1) * (b(1, 0) * b(2, 2) - b(1, 2) * b(2, 0))
+        + b(0, 2) * (b(1, 0) * b(2, 1) - b(1, 1) * b(2, 0));
+    let r = det / two;
+    let r = if r < -one {
+        -one
+    } else if r > one {
+        one
+    } else {
+        r
+    };
+
+    let phi = r.acos() / three;
+    let max = q + two * p * phi.cos();
+    let min = q + two * p * (phi + T::from_f64(2.0 * std::f64::consts::FRAC_PI_3)).cos();
+    let mid = three * q - max - min;
+
+    let tol = T::EPSILON.sqrt() * (max.abs() + min.abs());
+    if max - mid <= tol || mid - min <= tol {
+        return None;
+    }
+
+    let v_max = null_vector(a, max)?;
+    let v_min = null_vector(a, min)?;
+    let v_mid = cross(v_max, v_min);
+
+    let values = [min, mid, max];
+    let vectors = [v_min, v_mid, v_max];
+    Some((
+        array::from_fn(|k| values[k]),
+        Matrix::from_rows(array::from_fn(|i| array::from_fn(|k| vectors[k][i]))),
+    ))
+}
+
+/// Finds the unit vector spanning the null space of `A - λI` as the largest
+/// cross product of its rows.
+fn null_vector<T, const M: usize>(a: &Matrix<T, M, M>, lambda: T) -> Option<[T; 3]>
+where
+    T: Real,
+{
+    let rows: [[T; 3]; 3] = array::from_fn(|i| {
+        array::from_fn(|j| a[(i, j)] - if i == j { lambda } else { T::id_add() })
+    });
+
+    [(0, 1), (0, 2), (1, 2)]
+        .map(|(i, j)| cross(rows[i], rows[j]))
+        .into_iter()
+        .map(|v| (dot(v, v), v))
+        .fold(None, |best: Option<(T, [T; 3])>, (n, v)| match best {
+            Some((m, _)) if m >= n => best,
+            _ => Some((n, v)),
+        })
+        .filter(|&(n, _)| n > T::id_add())
+        .map(|(n, v)| v.map(|x| x / n.sqrt()))
+}
+
+fn cross<T>(a: [T; 3], b: [T; 3]) -> [T; 3]
+where
+    T: Real,
+{
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot<T>(a: [T; 3], b: [T; 3]) -> T
+where
+    T: Real,
+{
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::IdAdd;
+    use crate::testing::assert_approx;
+
+    fn assert_decomposes<const M: usize>(mat: Matrix<f64, M, M>) {
+        let eigen = mat.clone().symmetric_eigen().unwrap();
+        let v = eigen.eigenvectors().clone();
+        let lambda =
+            Matrix::<f64, M, M>::id_add()
+                .into_map(|i, j, x| if i == j { eigen.eigenvalues()[i] } else { x });
+
+        assert!(eigen.eigenvalues().windows(2).all(|w| w[0] <= w[1]));
+        assert_approx(Matrix::id_mul(), v.clone().transpose() * v.clone(), 1e-10);
+        assert_approx(mat * v.clone(), v * lambda, 1e-10);
+    }
+
+    #[test]
+    fn closed_form3x3() {
+        let mat = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        let eigen = mat.clone().symmetric_eigen().unwrap();
+        let sqrt2 = 2.0_f64.sqrt();
+
+        assert!(closed_form(&mat).is_some());
+        [2.0 - sqrt2, 2.0, 2.0 + sqrt2]
+            .iter()
+            .zip(eigen.eigenvalues())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-12));
+        assert_decomposes(mat);
+    }
+
+    #[test]
+    fn repeated3x3() {
+        let mat = Matrix::from_rows([[2.0, 1.0, 1.0], [1.0, 2.0, 1.0], [1.0, 1.0, 2.0]]);
+        let eigen = mat.clone().symmetric_eigen().unwrap();
+
+        assert!(closed_form(&mat).is_none());
+        [1.0_f64, 1.0, 4.0]
+            .iter()
+            .zip(eigen.eigenvalues())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-12));
+        assert_decomposes(mat);
+    }
+
+    #[test]
+    fn jacobi4x4() {
+        assert_decomposes(Matrix::from_rows([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]));
+    }
+
+    #[test]
+    fn diagonal() {
+        let eigen = Matrix::from_rows([[3.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 2.0]])
+            .symmetric_eigen()
+            .unwrap();
+
+        assert_eq!(&[-1.0, 2.0, 3.0], eigen.eigenvalues());
+        assert_eq!(
+            &Matrix::from_rows([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+            eigen.eigenvectors()
+        );
+    }
+
+    #[test]
+    fn asymmetric() {
+        assert!(Matrix::from_rows([[1.0, 2.0], [0.0, 1.0]])
+            .symmetric_eigen()
+            .is_none());
+    }
+}
@@ -0,0 +1,559 @@
+use super::Matrix;
+use crate::{
+    dmatrix::{DMatrixView, DMatrixViewMut},
+    identity::IdAdd,
+};
+use std::{
+    array,
+    fmt::{self, Debug, Formatter},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Range, Sub, SubAssign},
+};
+
+/// Borrowed `R x C` block of a matrix. Element `(i, j)` of the view lies at
+/// `i * row_stride + j * col_stride` in the borrowed storage.
+pub struct MatrixView<'a, T, const R: usize, const C: usize> {
+    data: &'a [T],
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Mutable counterpart of [`MatrixView`], writing straight into the parent.
+pub struct MatrixViewMut<'a, T, const R: usize, const C: usize> {
+    data: &'a mut [T],
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Returns the range of the parent's storage spanned by the `size` block at
+/// `start` of a `shape` matrix laid out with `strides`.
+pub(crate) fn window(
+    shape: (usize, usize),
+    strides: (usize, usize),
+    start: (usize, usize),
+    size: (usize, usize),
+) -> Range<usize> {
+    assert!(
+        start.0 + size.0 <= shape.0 && start.1 + size.1 <= shape.1,
+        "cannot take a {}x{} view at ({}, {}) of a {}x{} matrix",
+        size.0,
+        size.1,
+        start.0,
+        start.1,
+        shape.0,
+        shape.1
+    );
+
+    if size.0 == 0 || size.1 == 0 {
+        return 0..0;
+    }
+
+    let offset = start.0 * strides.0 + start.1 * strides.1;
+    offset..offset + (size.0 - 1) * strides.0 + (size.1 - 1) * strides.1 + 1
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Borrows the `R x C` block whose top-left corner is `(i0, j0)`.
+    pub fn view<const R: usize, const C: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'_, T, R, C> {
+        let range = window((M, N), (N, 1), (i0, j0), (R, C));
+        MatrixView::from_raw(&self.0.as_flattened()[range], N, 1)
+    }
+
+    pub fn view_mut<const R: usize, const C: usize>(
+        &mut self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixViewMut<'_, T, R, C> {
+        let range = window((M, N), (N, 1), (i0, j0), (R, C));
+        MatrixViewMut::from_raw(&mut self.0.as_flattened_mut()[range], N, 1)
+    }
+
+    /// Borrows the `nrows x ncols` block whose top-left corner is `(i0, j0)`.
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> DMatrixView<'_, T> {
+        self.as_view().view_dyn(i0, j0, nrows, ncols)
+    }
+
+    pub fn view_dyn_mut(
+        &mut self,
+        i0: usize,
+        j0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> DMatrixViewMut<'_, T> {
+        DMatrixViewMut::from(self.as_view_mut()).into_view_dyn_mut(i0, j0, nrows, ncols)
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T, M, N> {
+        self.view(0, 0)
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T, M, N> {
+        self.view_mut(0, 0)
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixView<'a, T, R, C> {
+    pub(crate) fn from_raw(data: &'a [T], row_stride: usize, col_stride: usize) -> Self {
+        Self {
+            data,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    pub(crate) fn into_raw(self) -> (&'a [T], usize, usize) {
+        (self.data, self.row_stride, self.col_stride)
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        (i < R && j < C).then(|| &self.data[i * self.row_stride + j * self.col_stride])
+    }
+
+    /// Iterates over the elements in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..R).flat_map(move |i| {
+            (0..C).map(move |j| &view.data[i * view.row_stride + j * view.col_stride])
+        })
+    }
+
+    pub fn row(&self, i: usize) -> Option<[&'a T; C]> {
+        (i < R).then(|| array::from_fn(|j| &self.data[i * self.row_stride + j * self.col_stride]))
+    }
+
+    pub fn col(&self, j: usize) -> Option<[&'a T; R]> {
+        (j < C).then(|| array::from_fn(|i| &self.data[i * self.row_stride + j * self.col_stride]))
+    }
+
+    /// Borrows the `P x Q` block of this view whose top-left corner is `(i0, j0)`.
+    pub fn view<const P: usize, const Q: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'a, T, P, Q> {
+        let range = window((R, C), (self.row_stride, self.col_stride), (i0, j0), (P, Q));
+        MatrixView::from_raw(&self.data[range], self.row_stride, self.col_stride)
+    }
+
+    /// Borrows a runtime-sized block of this view.
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> DMatrixView<'a, T> {
+        DMatrixView::from(*self).view_dyn(i0, j0, nrows, ncols)
+    }
+
+    /// Swaps the strides, so no elements are moved.
+    pub fn transpose(self) -> MatrixView<'a, T, C, R> {
+        MatrixView::from_raw(self.data, self.col_stride, self.row_stride)
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Matrix<U, R, C>
+    where
+        F: FnMut(usize, usize, &T) -> U,
+    {
+        Matrix::from_rows(array::from_fn(|i| {
+            array::from_fn(|j| f(i, j, &self[(i, j)]))
+        }))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, R, C>
+    where
+        T: Clone,
+    {
+        self.map(|_, _, x| x.clone())
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixViewMut<'a, T, R, C> {
+    pub(crate) fn from_raw(data: &'a mut [T], row_stride: usize, col_stride: usize) -> Self {
+        Self {
+            data,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T, R, C> {
+        MatrixView::from_raw(self.data, self.row_stride, self.col_stride)
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T, R, C> {
+        MatrixViewMut::from_raw(self.data, self.row_stride, self.col_stride)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.as_view().get(i, j)
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        (i < R && j < C).then(|| &mut self.data[i * self.row_stride + j * self.col_stride])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_view().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (row_stride, col_stride) = (self.row_stride, self.col_stride);
+        let ptr = self.data.as_mut_ptr();
+
+        // SAFETY: `R` and `C` are part of the type. Every `view_mut` that
+        // returns a `MatrixViewMut<R, C>` slices `data` to the range `window()`
+        // gives for an `R x C` block, and `as_view_mut` and `transpose` reuse
+        // such a slice, the latter swapping the strides along with `R` and
+        // `C`. So `(R - 1) * row_stride + (C - 1) * col_stride`, the largest
+        // offset below, is the last index of `data`. One stride is 1 and the
+        // other is the row length of the underlying storage, which is at least
+        // the extent along the unit stride, so no two positions share an
+        // offset and the references never alias.
+        (0..R).flat_map(move |i| {
+            (0..C).map(move |j| unsafe { &mut *ptr.add(i * row_stride + j * col_stride) })
+        })
+    }
+
+    pub fn view<const P: usize, const Q: usize>(
+        &self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixView<'_, T, P, Q> {
+        self.as_view().view(i0, j0)
+    }
+
+    pub fn view_mut<const P: usize, const Q: usize>(
+        &mut self,
+        i0: usize,
+        j0: usize,
+    ) -> MatrixViewMut<'_, T, P, Q> {
+        let range = window((R, C), (self.row_stride, self.col_stride), (i0, j0), (P, Q));
+        MatrixViewMut::from_raw(&mut self.data[range], self.row_stride, self.col_stride)
+    }
+
+    pub fn view_dyn(&self, i0: usize, j0: usize, nrows: usize, ncols: usize) -> DMatrixView<'_, T> {
+        self.as_view().view_dyn(i0, j0, nrows, ncols)
+    }
+
+    pub fn view_dyn_mut(
+        &mut self,
+        i0: usize,
+        j0: usize,
+        nrows: usize,
+        ncols: usize,
+    ) -> DMatrixViewMut<'_, T> {
+        DMatrixViewMut::from(self.as_view_mut()).into_view_dyn_mut(i0, j0, nrows, ncols)
+    }
+
+    pub(crate) fn into_raw(self) -> (&'a mut [T], usize, usize) {
+        (self.data, self.row_stride, self.col_stride)
+    }
+
+    pub fn transpose(self) -> MatrixViewMut<'a, T, C, R> {
+        MatrixViewMut::from_raw(self.data, self.col_stride, self.row_stride)
+    }
+
+    pub fn map_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        for i in 0..R {
+            for j in 0..C {
+                f(i, j, &mut self[(i, j)]);
+            }
+        }
+    }
+
+    /// Copies `src` into the parent.
+    pub fn assign(&mut self, src: MatrixView<'_, T, R, C>)
+    where
+        T: Clone,
+    {
+        self.map_mut(|i, j, x| x.clone_from(&src[(i, j)]));
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.map_mut(|_, _, x| *x = value.clone());
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, R, C>
+    where
+        T: Clone,
+    {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Clone for MatrixView<'_, T, R, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const R: usize, const C: usize> Copy for MatrixView<'_, T, R, C> {}
+
+impl<'a, T, const R: usize, const C: usize> From<MatrixViewMut<'a, T, R, C>>
+    for MatrixView<'a, T, R, C>
+{
+    fn from(value: MatrixViewMut<'a, T, R, C>) -> Self {
+        Self::from_raw(value.data, value.row_stride, value.col_stride)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Debug for MatrixView<'_, T, R, C>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..R).map(|i| self.row(i).unwrap()))
+            .finish()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Debug for MatrixViewMut<'_, T, R, C>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+impl<T, U, const R: usize, const C: usize> PartialEq<MatrixView<'_, U, R, C>>
+    for MatrixView<'_, T, R, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &MatrixView<'_, U, R, C>) -> bool {
+        self.iter().zip(other.iter()).all(|(x, y)| x == y)
+    }
+}
+
+impl<T, U, const R: usize, const C: usize> PartialEq<Matrix<U, R, C>> for MatrixView<'_, T, R, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Matrix<U, R, C>) -> bool {
+        *self == other.as_view()
+    }
+}
+
+impl<T, U, const R: usize, const C: usize> PartialEq<MatrixView<'_, U, R, C>> for Matrix<T, R, C>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &MatrixView<'_, U, R, C>) -> bool {
+        self.as_view() == *other
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixView<'_, T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                R, C, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixViewMut<'_, T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                R, C, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for MatrixViewMut<'_, T, R, C> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index.0, index.1).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+                R, C, index.0, index.1
+            )
+        })
+    }
+}
+
+impl<T, U, V, const R: usize, const C: usize> Add<MatrixView<'_, U, R, C>>
+    for MatrixView<'_, T, R, C>
+where
+    for<'a, 'b> &'a T: Add<&'b U, Output = V>,
+{
+    type Output = Matrix<V, R, C>;
+
+    fn add(self, rhs: MatrixView<'_, U, R, C>) -> Self::Output {
+        self.map(|i, j, x| x + &rhs[(i, j)])
+    }
+}
+
+impl<T, U, V, const R: usize, const C: usize> Sub<MatrixView<'_, U, R, C>>
+    for MatrixView<'_, T, R, C>
+where
+    for<'a, 'b> &'a T: Sub<&'b U, Output = V>,
+{
+    type Output = Matrix<V, R, C>;
+
+    fn sub(self, rhs: MatrixView<'_, U, R, C>) -> Self::Output {
+        self.map(|i, j, x| x - &rhs[(i, j)])
+    }
+}
+
+impl<T, U, V, W, const R: usize, const C: usize, const P: usize> Mul<MatrixView<'_, U, C, P>>
+    for MatrixView<'_, T, R, C>
+where
+    for<'a, 'b> &'a T: Mul<&'b U, Output = V>,
+    V: Add<V, Output = W>,
+    W: Add<V, Output = W> + IdAdd,
+{
+    type Output = Matrix<W, R, P>;
+
+    fn mul(self, rhs: MatrixView<'_, U, C, P>) -> Self::Output {
+        Matrix::from_rows(array::from_fn(|i| {
+            array::from_fn(|j| (0..C).fold(W::id_add(), |x, k| x + &self[(i, k)] * &rhs[(k, j)]))
+        }))
+    }
+}
+
+impl<T, U, const R: usize, const C: usize> AddAssign<MatrixView<'_, U, R, C>>
+    for MatrixViewMut<'_, T, R, C>
+where
+    for<'a> T: AddAssign<&'a U>,
+{
+    fn add_assign(&mut self, rhs: MatrixView<'_, U, R, C>) {
+        self.map_mut(|i, j, x| *x += &rhs[(i, j)]);
+    }
+}
+
+impl<T, U, const R: usize, const C: usize> SubAssign<MatrixView<'_, U, R, C>>
+    for MatrixViewMut<'_, T, R, C>
+where
+    for<'a> T: SubAssign<&'a U>,
+{
+    fn sub_assign(&mut self, rhs: MatrixView<'_, U, R, C>) {
+        self.map_mut(|i, j, x| *x -= &rhs[(i, j)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::sample;
+
+    #[test]
+    fn view() {
+        let mat: Matrix<i32, 4, 5> = sample(0);
+        let view = mat.view::<2, 3>(1, 2);
+
+        assert_eq!(Matrix::from_rows([[8, 2, -4], [-7, 10, 4]]), view);
+        assert_eq!(10, view[(1, 1)]);
+        assert_eq!(None, view.get(2, 0));
+        assert_eq!(Some([&2, &10]), view.col(1));
+        assert_eq!(
+            vec![8, 2, -4, -7, 10, 4],
+            view.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn nested_view() {
+        let mat: Matrix<i32, 4, 5> = sample(0);
+        let view = mat.view::<3, 4>(1, 1).view::<2, 2>(1, 2);
+
+        assert_eq!(Matrix::from_rows([[10, 4], [-5, -11]]), view);
+    }
+
+    #[test]
+    fn transpose() {
+        let mat: Matrix<i32, 4, 5> = sample(0);
+        let view = mat.view::<2, 3>(0, 1).transpose();
+
+        assert_eq!((1, 5), (view.row_stride(), view.col_stride()));
+        assert_eq!(Matrix::from_rows([[6, -9], [0, 8], [-6, 2]]), view);
+        assert_eq!(Matrix::from_rows([[0, 8]]), view.view::<1, 2>(1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take a 2x3 view at (3, 0) of a 4x5 matrix")]
+    fn view_out_of_bounds() {
+        let _ = sample::<i32, 4, 5>(0).view::<2, 3>(3, 0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mat: Matrix<i32, 4, 5> = sample(0);
+        let a = mat.view::<2, 2>(0, 0);
+        let b = mat.view::<2, 2>(2, 3);
+
+        assert_eq!(Matrix::from_rows([[-1, 10], [-8, -20]]), a + b);
+        assert_eq!(Matrix::from_rows([[-21, 2], [2, 2]]), a - b);
+        assert_eq!(Matrix::from_rows([[-140, -110], [15, 87]]), a * b);
+        assert_eq!(
+            mat.view::<2, 3>(0, 0).to_matrix() * mat.view::<3, 2>(1, 1).to_matrix(),
+            mat.view::<2, 3>(0, 0) * mat.view::<3, 2>(1, 1)
+        );
+    }
+
+    #[test]
+    fn assign() {
+        let mut mat: Matrix<i32, 4, 5> = sample(0);
+        let block = Matrix::from_rows([[0, -1], [-2, -3]]);
+
+        mat.view_mut::<2, 2>(1, 3).assign(block.as_view());
+        assert_eq!(
+            Matrix::from_rows([
+                [-11, 6, 0, -6, 11],
+                [-3, -9, 8, 0, -1],
+                [5, -1, -7, -2, -3],
+                [-10, 7, 1, -5, -11],
+            ]),
+            mat
+        );
+
+        let mut view = mat.view_mut::<2, 2>(0, 0);
+        view += block.as_view();
+        view.view_mut::<1, 2>(1, 0).fill(0);
+        view.iter_mut().for_each(|x| *x *= 10);
+        assert_eq!(Matrix::from_rows([[-110, 50], [0, 0]]), view.to_matrix());
+        assert_eq!(
+            Matrix::from_rows([[-110, 50, 0], [0, 0, 8]]),
+            mat.view::<2, 3>(0, 0)
+        );
+    }
+
+    #[test]
+    fn transpose_mut() {
+        let mut mat = Matrix::from_rows([[1, 2], [3, 4]]);
+        let mut view = mat.as_view_mut().transpose();
+        view[(0, 1)] = 0;
+
+        assert_eq!(Matrix::from_rows([[1, 2], [0, 4]]), mat);
+    }
+}
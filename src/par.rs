@@ -0,0 +1,26 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Smallest number of items worth spreading across threads by default.
+#[cfg(feature = "rayon")]
+pub(crate) const MIN_LEN: usize = 64;
+
+/// Calls `f` with the index of every item and a mutable reference to it,
+/// spreading the calls across the rayon thread pool when the `rayon` feature
+/// is enabled and there are at least `min_len` items.
+pub(crate) fn for_each_mut<T, F>(items: &mut [T], min_len: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut T) + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    if items.len() >= min_len {
+        items.par_iter_mut().enumerate().for_each(|(i, x)| f(i, x));
+        return;
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = min_len;
+
+    items.iter_mut().enumerate().for_each(|(i, x)| f(i, x));
+}
@@ -1,6 +1,32 @@
+pub use crate::complex::*;
+pub use crate::dmatrix::*;
 pub use crate::identity::*;
 pub use crate::matrix::aliases::*;
+pub use crate::matrix::cholesky::Cholesky;
 pub use crate::matrix::iter::cursor::CursorIterator;
+pub use crate::matrix::ldl::Ldl;
+pub use crate::matrix::lu::Lu;
+pub use crate::matrix::qr::Qr;
+pub use crate::matrix::schur::{Hessenberg, Schur};
+pub use crate::matrix::solve::SolveError;
+pub use crate::matrix::strassen::STRASSEN_CUTOFF;
+pub use crate::matrix::subspace::Orthonormalization;
+pub use crate::matrix::svd::Svd;
+pub use crate::matrix::symmetric_eigen::SymmetricEigen;
+pub use crate::matrix::view::{MatrixView, MatrixViewMut};
 pub use crate::matrix::*;
+pub use crate::scalar::*;
+pub use crate::solvers::{
+    bicgstab::bicgstab,
+    cg::cg,
+    gmres::gmres,
+    operator::LinearOperator,
+    preconditioner::{Identity, Ilu0, Jacobi, Preconditioner},
+    Convergence, SolverConfig,
+};
+pub use crate::sparse::{
+    cholesky::SparseCholesky, coo::CooMatrix, csc::CscMatrix, csr::CsrMatrix, lu::SparseLu,
+    ordering::FillOrdering,
+};
 pub use crate::vec2::*;
 pub use crate::vec3::*;
@@ -0,0 +1,91 @@
+use crate::identity::{IdAdd, IdMul};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[macro_use]
+mod macros;
+
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + IdAdd
+    + IdMul
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Neg<Output = Self>
+{
+    /// Whether arithmetic on `Self` is exact, as is the case for integers.
+    const EXACT: bool;
+
+    /// Distance from one to the next representable value, which is zero for exact types.
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+
+    fn from_usize(n: usize) -> Self;
+}
+
+pub trait Real: Scalar {
+    fn from_f64(x: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn is_finite(self) -> bool;
+
+    fn sqrt(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn acos(self) -> Self;
+}
+
+scalar_impl! {
+    Self = i8,
+    exact = true,
+    epsilon = 0,
+}
+
+scalar_impl! {
+    Self = i16,
+    exact = true,
+    epsilon = 0,
+}
+
+scalar_impl! {
+    Self = i32,
+    exact = true,
+    epsilon = 0,
+}
+
+scalar_impl! {
+    Self = i64,
+    exact = true,
+    epsilon = 0,
+}
+
+scalar_impl! {
+    Self = isize,
+    exact = true,
+    epsilon = 0,
+}
+
+scalar_impl! {
+    Self = f32,
+    exact = false,
+    epsilon = f32::EPSILON,
+}
+
+scalar_impl! {
+    Self = f64,
+    exact = false,
+    epsilon = f64::EPSILON,
+}
+
+real_impl! {
+    Self = f32,
+}
+
+real_impl! {
+    Self = f64,
+}
@@ -0,0 +1,54 @@
+#[macro_export]
+macro_rules! scalar_impl {
+    (
+        Self = $Self:ty,
+        exact = $exact:literal,
+        epsilon = $epsilon:expr,
+    ) => {
+        impl Scalar for $Self {
+            const EXACT: bool = $exact;
+            const EPSILON: Self = $epsilon;
+
+            fn abs(self) -> Self {
+                <$Self>::abs(self)
+            }
+
+            fn from_usize(n: usize) -> Self {
+                n as $Self
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! real_impl {
+    (
+        Self = $Self:ty,
+    ) => {
+        impl Real for $Self {
+            fn from_f64(x: f64) -> Self {
+                x as $Self
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn is_finite(self) -> bool {
+                <$Self>::is_finite(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$Self>::sqrt(self)
+            }
+
+            fn cos(self) -> Self {
+                <$Self>::cos(self)
+            }
+
+            fn acos(self) -> Self {
+                <$Self>::acos(self)
+            }
+        }
+    };
+}
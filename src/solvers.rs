@@ -0,0 +1,111 @@
+use crate::scalar::Real;
+
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+pub mod operator;
+pub mod preconditioner;
+
+/// Stopping criteria shared by the iterative solvers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverConfig<T> {
+    /// Target for the residual norm relative to the norm of the right-hand side.
+    pub tol: T,
+    pub max_iter: usize,
+}
+
+impl<T> SolverConfig<T> {
+    pub fn new(tol: T, max_iter: usize) -> Self {
+        Self { tol, max_iter }
+    }
+}
+
+impl<T> Default for SolverConfig<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new(T::from_f64(1e-10), 1000)
+    }
+}
+
+/// Outcome of an iterative solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convergence<T> {
+    pub converged: bool,
+    pub iterations: usize,
+    /// Relative residual norm of the initial guess followed by that of every iteration.
+    pub history: Vec<T>,
+    /// Whether a sufficient condition for convergence held before iterating,
+    /// for the solvers that check one. `Some(false)` does not mean that the
+    /// iteration diverges, only that nothing promised it would converge.
+    pub guaranteed: Option<bool>,
+}
+
+impl<T> Convergence<T>
+where
+    T: Copy,
+{
+    /// Returns the last relative residual norm.
+    pub fn residual(&self) -> T {
+        *self.history.last().expect("history is never empty")
+    }
+}
+
+fn dot<T>(x: &[T], y: &[T]) -> T
+where
+    T: Real,
+{
+    x.iter()
+        .zip(y)
+        .fold(T::id_add(), |sum, (&x, &y)| sum + x * y)
+}
+
+fn norm<T>(x: &[T]) -> T
+where
+    T: Real,
+{
+    dot(x, x).sqrt()
+}
+
+/// Computes `y += alpha * x`.
+fn axpy<T>(alpha: T, x: &[T], y: &mut [T])
+where
+    T: Real,
+{
+    y.iter_mut().zip(x).for_each(|(y, &x)| *y = *y + alpha * x);
+}
+
+/// Computes `r = b - A * x`.
+fn residual<T, A>(a: &A, b: &[T], x: &[T], r: &mut [T])
+where
+    T: Real,
+    A: operator::LinearOperator<T> + ?Sized,
+{
+    a.apply(x, r);
+    r.iter_mut().zip(b).for_each(|(r, &b)| *r = b - *r);
+}
+
+/// Validates the dimensions of `A * x = b` and returns `‖b‖`, falling back to
+/// one for a zero right-hand side so that residuals stay absolute.
+fn prepare<T, A>(a: &A, b: &[T], x: &[T]) -> T
+where
+    T: Real,
+    A: operator::LinearOperator<T> + ?Sized,
+{
+    assert!(
+        a.nrows() == a.ncols() && a.nrows() == b.len() && a.ncols() == x.len(),
+        "cannot solve a {}x{} system with a right-hand side of length {} and a guess of length {}",
+        a.nrows(),
+        a.ncols(),
+        b.len(),
+        x.len()
+    );
+
+    let bnorm = norm(b);
+    if bnorm == T::id_add() {
+        T::id_mul()
+    } else {
+        bnorm
+    }
+}
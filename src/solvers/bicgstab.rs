@@ -0,0 +1,151 @@
+use super::{
+    axpy, dot, norm, operator::LinearOperator, preconditioner::Preconditioner, prepare, residual,
+    Convergence, SolverConfig,
+};
+use crate::scalar::Real;
+
+/// Solves `A * x = b` for a general `A` by the right-preconditioned
+/// biconjugate gradient stabilized method, starting from the guess in `x`.
+pub fn bicgstab<T, A, P>(
+    a: &A,
+    b: &[T],
+    x: &mut [T],
+    precond: &P,
+    config: &SolverConfig<T>,
+) -> Convergence<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+    P: Preconditioner<T> + ?Sized,
+{
+    let bnorm = prepare(a, b, x);
+    let n = b.len();
+
+    let mut r = vec![T::id_add(); n];
+    residual(a, b, x, &mut r);
+    let r_hat = r.clone();
+
+    let mut p = vec![T::id_add(); n];
+    let mut v = vec![T::id_add(); n];
+    let mut p_hat = vec![T::id_add(); n];
+    let mut s_hat = vec![T::id_add(); n];
+    let mut t = vec![T::id_add(); n];
+    let (mut rho, mut alpha, mut omega) = (T::id_mul(), T::id_mul(), T::id_mul());
+
+    let mut history = vec![norm(&r) / bnorm];
+    let mut iterations = 0;
+    while history[iterations] > config.tol && iterations < config.max_iter {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == T::id_add() || omega == T::id_add() {
+            break;
+        }
+
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        p.iter_mut()
+            .zip(&r)
+            .zip(&v)
+            .for_each(|((p, &r), &v)| *p = r + beta * (*p - omega * v));
+
+        precond.apply(&p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+        let rv = dot(&r_hat, &v);
+        if rv == T::id_add() {
+            break;
+        }
+        alpha = rho / rv;
+        axpy(-alpha, &v, &mut r);
+        axpy(alpha, &p_hat, x);
+        iterations += 1;
+
+        let snorm = norm(&r) / bnorm;
+        if snorm <= config.tol {
+            history.push(snorm);
+            break;
+        }
+
+        precond.apply(&r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+        let tt = dot(&t, &t);
+        omega = if tt == T::id_add() {
+            T::id_add()
+        } else {
+            dot(&t, &r) / tt
+        };
+        axpy(omega, &s_hat, x);
+        axpy(-omega, &t, &mut r);
+        history.push(norm(&r) / bnorm);
+    }
+
+    Convergence {
+        converged: history[iterations] <= config.tol,
+        iterations,
+        history,
+        guaranteed: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        dmatrix::DMatrix,
+        solvers::preconditioner::{Identity, Ilu0},
+        sparse::csr::CsrMatrix,
+        testing::convection_diffusion,
+    };
+
+    #[test]
+    fn dense() {
+        let a = DMatrix::from_rows(vec![[3.0, 1.0, 0.0], [-1.0, 4.0, 2.0], [0.0, -2.0, 5.0]]);
+        let mut x = [0.0; 3];
+        let result = bicgstab(
+            &a,
+            &[4.0, 5.0, 3.0],
+            &mut x,
+            &Identity,
+            &SolverConfig::default(),
+        );
+
+        assert!(result.converged);
+        x.iter()
+            .for_each(|&x| assert!((1.0_f64 - x).abs() < 1e-9, "{}", x));
+    }
+
+    #[test]
+    fn preconditioned() {
+        let a = CsrMatrix::from(convection_diffusion(16));
+        let expected: Vec<f64> = (0..a.nrows()).map(|i| (i as f64).cos()).collect();
+        let b = a.mul_vec(&expected);
+        let config = SolverConfig::new(1e-10, 500);
+
+        let mut x = vec![0.0; a.nrows()];
+        let plain = bicgstab(&a, &b, &mut x, &Identity, &config);
+        assert!(plain.converged);
+
+        let mut x = vec![0.0; a.nrows()];
+        let result = bicgstab(&a, &b, &mut x, &Ilu0::new(&a).unwrap(), &config);
+        assert!(result.converged);
+        assert!(result.iterations < plain.iterations);
+        assert_eq!(result.iterations + 1, result.history.len());
+        x.iter()
+            .zip(&expected)
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-8));
+    }
+
+    #[test]
+    fn zero_rhs() {
+        let a = CsrMatrix::from(convection_diffusion(4));
+        let mut x = vec![0.0; a.nrows()];
+        let result = bicgstab(
+            &a,
+            &vec![0.0; a.nrows()],
+            &mut x,
+            &Identity,
+            &SolverConfig::default(),
+        );
+
+        assert!(result.converged);
+        assert_eq!(0, result.iterations);
+    }
+}
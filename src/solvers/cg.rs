@@ -0,0 +1,124 @@
+use super::{
+    axpy, dot, norm, operator::LinearOperator, preconditioner::Preconditioner, prepare, residual,
+    Convergence, SolverConfig,
+};
+use crate::scalar::Real;
+
+/// Solves `A * x = b` for a symmetric positive-definite `A` by the
+/// preconditioned conjugate gradient method, starting from the guess in `x`.
+pub fn cg<T, A, P>(
+    a: &A,
+    b: &[T],
+    x: &mut [T],
+    precond: &P,
+    config: &SolverConfig<T>,
+) -> Convergence<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+    P: Preconditioner<T> + ?Sized,
+{
+    let bnorm = prepare(a, b, x);
+    let n = b.len();
+
+    let mut r = vec![T::id_add(); n];
+    residual(a, b, x, &mut r);
+    let mut z = vec![T::id_add(); n];
+    precond.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut q = vec![T::id_add(); n];
+    let mut rz = dot(&r, &z);
+
+    let mut history = vec![norm(&r) / bnorm];
+    let mut iterations = 0;
+    while history[iterations] > config.tol && iterations < config.max_iter {
+        a.apply(&p, &mut q);
+        let pq = dot(&p, &q);
+        if pq == T::id_add() {
+            break;
+        }
+
+        let alpha = rz / pq;
+        axpy(alpha, &p, x);
+        axpy(-alpha, &q, &mut r);
+        iterations += 1;
+        history.push(norm(&r) / bnorm);
+
+        precond.apply(&r, &mut z);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p.iter_mut().zip(&z).for_each(|(p, &z)| *p = z + beta * *p);
+    }
+
+    Convergence {
+        converged: history[iterations] <= config.tol,
+        iterations,
+        history,
+        guaranteed: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        matrix::Matrix,
+        solvers::preconditioner::{Identity, Ilu0, Jacobi},
+        sparse::csr::CsrMatrix,
+        testing::poisson,
+    };
+
+    #[test]
+    fn dense() {
+        let a = Matrix::from_rows([[4.0, 1.0], [1.0, 3.0]]);
+        let mut x = [0.0; 2];
+        let result = cg(&a, &[1.0, 2.0], &mut x, &Identity, &SolverConfig::default());
+
+        // CG terminates in at most `n` steps in exact arithmetic.
+        assert!(result.converged);
+        assert_eq!(2, result.iterations);
+        assert!((1.0_f64 / 11.0 - x[0]).abs() < 1e-12);
+        assert!((7.0_f64 / 11.0 - x[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn preconditioned() {
+        let a = CsrMatrix::from(poisson(16));
+        let expected: Vec<f64> = (0..a.nrows()).map(|i| (i as f64).sin()).collect();
+        let b = a.mul_vec(&expected);
+        let config = SolverConfig::new(1e-10, 500);
+
+        let diag: Vec<f64> = (0..a.nrows()).map(|i| *a.get(i, i).unwrap()).collect();
+        let jacobi = Jacobi::new(&diag).unwrap();
+        let ilu = Ilu0::new(&a).unwrap();
+
+        let mut x = vec![0.0; a.nrows()];
+        let plain = cg(&a, &b, &mut x, &Identity, &config);
+        assert!(plain.converged);
+        assert_eq!(plain.iterations + 1, plain.history.len());
+
+        let mut x = vec![0.0; a.nrows()];
+        assert!(cg(&a, &b, &mut x, &jacobi, &config).converged);
+
+        let mut x = vec![0.0; a.nrows()];
+        let result = cg(&a, &b, &mut x, &ilu, &config);
+        assert!(result.converged);
+        assert!(result.iterations < plain.iterations);
+        x.iter()
+            .zip(&expected)
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-8));
+    }
+
+    #[test]
+    fn max_iter() {
+        let a = CsrMatrix::from(poisson(8));
+        let b = vec![1.0; a.nrows()];
+        let mut x = vec![0.0; a.nrows()];
+        let result = cg(&a, &b, &mut x, &Identity, &SolverConfig::new(1e-12, 3));
+
+        assert!(!result.converged);
+        assert_eq!(3, result.iterations);
+        assert_eq!(4, result.history.len());
+    }
+}
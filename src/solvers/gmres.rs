@@ -0,0 +1,183 @@
+use super::{
+    axpy, dot, norm, operator::LinearOperator, preconditioner::Preconditioner, prepare, residual,
+    Convergence, SolverConfig,
+};
+use crate::scalar::Real;
+
+/// Solves `A * x = b` for a general `A` by right-preconditioned GMRES that
+/// restarts after building a Krylov basis of `restart` vectors, starting from
+/// the guess in `x`.
+///
+/// Each inner step counts as an iteration, and its residual norm in the
+/// history is the one estimated by the least-squares problem.
+pub fn gmres<T, A, P>(
+    a: &A,
+    b: &[T],
+    x: &mut [T],
+    restart: usize,
+    precond: &P,
+    config: &SolverConfig<T>,
+) -> Convergence<T>
+where
+    T: Real,
+    A: LinearOperator<T> + ?Sized,
+    P: Preconditioner<T> + ?Sized,
+{
+    assert!(restart > 0, "cannot restart GMRES after zero iterations");
+
+    let bnorm = prepare(a, b, x);
+    let n = b.len();
+    let m = restart.min(n.max(1));
+
+    let mut r = vec![T::id_add(); n];
+    residual(a, b, x, &mut r);
+    let mut history = vec![norm(&r) / bnorm];
+    let mut iterations = 0;
+    let mut stagnated = false;
+
+    while history[iterations] > config.tol && iterations < config.max_iter {
+        let beta = norm(&r);
+        let mut v = vec![r.iter().map(|&r| r / beta).collect::<Vec<_>>()];
+        let mut z = Vec::with_capacity(m);
+        // Columns of the Hessenberg matrix, reduced to upper-triangular form on the fly.
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(m);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(m);
+        let mut g = vec![T::id_add(); m + 1];
+        g[0] = beta;
+
+        for j in 0..m {
+            let mut zj = vec![T::id_add(); n];
+            precond.apply(&v[j], &mut zj);
+            let mut w = vec![T::id_add(); n];
+            a.apply(&zj, &mut w);
+            z.push(zj);
+
+            let mut col = vec![T::id_add(); j + 2];
+            for (i, vi) in v.iter().enumerate() {
+                col[i] = dot(&w, vi);
+                axpy(-col[i], vi, &mut w);
+            }
+            col[j + 1] = norm(&w);
+            let breakdown = col[j + 1] == T::id_add();
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (x, y) = (col[i], col[i + 1]);
+                col[i] = c * x + s * y;
+                col[i + 1] = c * y - s * x;
+            }
+            let rho = (col[j] * col[j] + col[j + 1] * col[j + 1]).sqrt();
+            if rho == T::id_add() {
+                // `A * M⁻¹` maps the new basis vector to zero, so the Krylov
+                // space cannot grow any further.
+                stagnated = true;
+                break;
+            }
+            let (c, s) = (col[j] / rho, col[j + 1] / rho);
+            col[j] = rho;
+            col[j + 1] = T::id_add();
+            g[j + 1] = -s * g[j];
+            g[j] = c * g[j];
+            rotations.push((c, s));
+            h.push(col);
+
+            iterations += 1;
+            history.push(g[j + 1].abs() / bnorm);
+            if breakdown || history[iterations] <= config.tol || iterations >= config.max_iter {
+                break;
+            }
+
+            let wnorm = norm(&w);
+            v.push(w.iter().map(|&w| w / wnorm).collect());
+        }
+
+        let k = h.len();
+        let mut y = g[..k].to_vec();
+        for i in (0..k).rev() {
+            let yi = y[i] / h[i][i];
+            y[i] = yi;
+            for (yl, &hl) in y.iter_mut().zip(&h[i][..i]) {
+                *yl = *yl - hl * yi;
+            }
+        }
+        for (yi, zi) in y.iter().zip(&z) {
+            axpy(*yi, zi, x);
+        }
+
+        residual(a, b, x, &mut r);
+        history[iterations] = norm(&r) / bnorm;
+        if stagnated {
+            break;
+        }
+    }
+
+    Convergence {
+        converged: history[iterations] <= config.tol,
+        iterations,
+        history,
+        guaranteed: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        matrix::Matrix,
+        solvers::preconditioner::{Identity, Ilu0},
+        sparse::csr::CsrMatrix,
+        testing::convection_diffusion,
+    };
+
+    #[test]
+    fn full() {
+        // Without restarts, GMRES terminates in at most `n` steps in exact arithmetic.
+        let a = Matrix::from_rows([[1.0, 2.0, 0.0], [0.0, 1.0, 3.0], [4.0, 0.0, 1.0]]);
+        let mut x = [0.0; 3];
+        let result = gmres(
+            &a,
+            &[3.0, 4.0, 5.0],
+            &mut x,
+            3,
+            &Identity,
+            &SolverConfig::default(),
+        );
+
+        assert!(result.converged);
+        assert!(result.iterations <= 3);
+        x.iter()
+            .for_each(|&x| assert!((1.0_f64 - x).abs() < 1e-10, "{}", x));
+    }
+
+    #[test]
+    fn restarted() {
+        let a = CsrMatrix::from(convection_diffusion(16));
+        let expected: Vec<f64> = (0..a.nrows()).map(|i| (i as f64).cos()).collect();
+        let b = a.mul_vec(&expected);
+        let config = SolverConfig::new(1e-10, 1000);
+
+        let mut x = vec![0.0; a.nrows()];
+        let plain = gmres(&a, &b, &mut x, 20, &Identity, &config);
+        assert!(plain.converged);
+        assert_eq!(plain.iterations + 1, plain.history.len());
+
+        let mut x = vec![0.0; a.nrows()];
+        let result = gmres(&a, &b, &mut x, 20, &Ilu0::new(&a).unwrap(), &config);
+        assert!(result.converged);
+        assert!(result.iterations < plain.iterations);
+        x.iter()
+            .zip(&expected)
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-8));
+    }
+
+    #[test]
+    fn max_iter() {
+        let a = CsrMatrix::from(convection_diffusion(8));
+        let b = vec![1.0; a.nrows()];
+        let mut x = vec![0.0; a.nrows()];
+        let result = gmres(&a, &b, &mut x, 4, &Identity, &SolverConfig::new(1e-14, 6));
+
+        assert!(!result.converged);
+        assert_eq!(6, result.iterations);
+        assert!(result.residual() < result.history[0]);
+    }
+}
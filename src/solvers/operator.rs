@@ -0,0 +1,145 @@
+use crate::{
+    dmatrix::DMatrix,
+    matrix::Matrix,
+    scalar::Scalar,
+    sparse::{csc::CscMatrix, csr::CsrMatrix},
+};
+
+/// A linear map that only needs to be applied to vectors, which lets the
+/// iterative solvers run matrix-free.
+pub trait LinearOperator<T> {
+    fn nrows(&self) -> usize;
+
+    fn ncols(&self) -> usize;
+
+    /// Computes `y = A * x`.
+    fn apply(&self, x: &[T], y: &mut [T]);
+}
+
+impl<T, const M: usize, const N: usize> LinearOperator<T> for Matrix<T, M, N>
+where
+    T: Scalar,
+{
+    fn nrows(&self) -> usize {
+        M
+    }
+
+    fn ncols(&self) -> usize {
+        N
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        for (i, y) in y.iter_mut().enumerate() {
+            *y = (0..N).fold(T::id_add(), |sum, j| sum + self[(i, j)] * x[j]);
+        }
+    }
+}
+
+impl<T> LinearOperator<T> for DMatrix<T>
+where
+    T: Scalar,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        for (y, row) in y.iter_mut().zip(self.rows()) {
+            *y = row
+                .iter()
+                .zip(x)
+                .fold(T::id_add(), |sum, (&a, &x)| sum + a * x);
+        }
+    }
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Scalar,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        for (i, y) in y.iter_mut().enumerate() {
+            let (idx, values) = self.0.lane(i);
+            *y = idx
+                .iter()
+                .zip(values)
+                .fold(T::id_add(), |sum, (&j, &v)| sum + v * x[j]);
+        }
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Scalar,
+{
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        y.fill(T::id_add());
+        for (i, j, &v) in self.entries() {
+            y[i] = y[i] + v * x[j];
+        }
+    }
+}
+
+impl<T, A> LinearOperator<T> for &A
+where
+    A: LinearOperator<T> + ?Sized,
+{
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    fn apply(&self, x: &[T], y: &mut [T]) {
+        (**self).apply(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply<A: LinearOperator<i32>>(a: A, x: &[i32]) -> Vec<i32> {
+        let mut y = vec![0; a.nrows()];
+        a.apply(x, &mut y);
+        y
+    }
+
+    #[test]
+    fn matrix() {
+        let mat = Matrix::from_rows([[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 4]]);
+
+        assert_eq!(vec![7, 0, 22], apply(&mat, &[1, 2, 3, 4]));
+        assert_eq!(
+            vec![7, 0, 22],
+            apply(DMatrix::from(mat.clone()), &[1, 2, 3, 4])
+        );
+        assert_eq!(
+            vec![7, 0, 22],
+            apply(CsrMatrix::from(mat.clone()), &[1, 2, 3, 4])
+        );
+        assert_eq!(vec![7, 0, 22], apply(CscMatrix::from(mat), &[1, 2, 3, 4]));
+    }
+}
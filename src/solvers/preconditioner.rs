@@ -0,0 +1,190 @@
+use crate::{
+    scalar::Real,
+    sparse::{csr::CsrMatrix, Compressed},
+};
+
+const NONE: usize = usize::MAX;
+
+/// Approximates `A⁻¹` to speed up the convergence of an iterative solver.
+pub trait Preconditioner<T> {
+    /// Computes `z = M⁻¹ * r`.
+    fn apply(&self, r: &[T], z: &mut [T]);
+}
+
+/// Leaves the residual unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl<T> Preconditioner<T> for Identity
+where
+    T: Copy,
+{
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        z.copy_from_slice(r);
+    }
+}
+
+/// Scales the residual by the inverse of the diagonal of `A`.
+#[derive(Debug, Clone)]
+pub struct Jacobi<T> {
+    inv_diag: Vec<T>,
+}
+
+impl<T> Jacobi<T>
+where
+    T: Real,
+{
+    /// Returns `None` if the diagonal has a zero entry.
+    pub fn new(diagonal: &[T]) -> Option<Self> {
+        diagonal
+            .iter()
+            .map(|&d| (d != T::id_add()).then(|| T::id_mul() / d))
+            .collect::<Option<_>>()
+            .map(|inv_diag| Self { inv_diag })
+    }
+}
+
+impl<T> Preconditioner<T> for Jacobi<T>
+where
+    T: Real,
+{
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        for ((z, &r), &d) in z.iter_mut().zip(r).zip(&self.inv_diag) {
+            *z = r * d;
+        }
+    }
+}
+
+/// Incomplete LU factorization that keeps the sparsity pattern of `A`.
+#[derive(Debug, Clone)]
+pub struct Ilu0<T> {
+    lu: Compressed<T>,
+    diag: Vec<usize>,
+}
+
+impl<T> Ilu0<T>
+where
+    T: Real,
+{
+    /// Returns `None` if a pivot is missing from the pattern or becomes zero.
+    pub fn new(a: &CsrMatrix<T>) -> Option<Self> {
+        assert_eq!(
+            a.nrows(),
+            a.ncols(),
+            "cannot factor a non-square {}x{} matrix",
+            a.nrows(),
+            a.ncols()
+        );
+
+        let n = a.nrows();
+        let mut lu = a.0.clone();
+        let mut diag = vec![NONE; n];
+        let mut pos = vec![NONE; n];
+
+        for i in 0..n {
+            let (start, end) = (lu.ptr[i], lu.ptr[i + 1]);
+            for p in start..end {
+                pos[lu.idx[p]] = p;
+            }
+
+            for p in start..end {
+                let k = lu.idx[p];
+                if k >= i {
+                    break;
+                }
+
+                let lik = lu.values[p] / lu.values[diag[k]];
+                lu.values[p] = lik;
+                for q in diag[k] + 1..lu.ptr[k + 1] {
+                    let j = pos[lu.idx[q]];
+                    if j != NONE {
+                        lu.values[j] = lu.values[j] - lik * lu.values[q];
+                    }
+                }
+            }
+
+            diag[i] = pos[i];
+            if diag[i] == NONE || lu.values[diag[i]] == T::id_add() {
+                return None;
+            }
+
+            for p in start..end {
+                pos[lu.idx[p]] = NONE;
+            }
+        }
+
+        Some(Self { lu, diag })
+    }
+}
+
+impl<T> Preconditioner<T> for Ilu0<T>
+where
+    T: Real,
+{
+    fn apply(&self, r: &[T], z: &mut [T]) {
+        let lu = &self.lu;
+        z.copy_from_slice(r);
+
+        for i in 0..z.len() {
+            for p in lu.ptr[i]..self.diag[i] {
+                z[i] = z[i] - lu.values[p] * z[lu.idx[p]];
+            }
+        }
+
+        for i in (0..z.len()).rev() {
+            for p in self.diag[i] + 1..lu.ptr[i + 1] {
+                z[i] = z[i] - lu.values[p] * z[lu.idx[p]];
+            }
+            z[i] = z[i] / lu.values[self.diag[i]];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn jacobi() {
+        let jacobi = Jacobi::new(&[2.0, 4.0, -0.5]).unwrap();
+        let mut z = [0.0; 3];
+        jacobi.apply(&[1.0, 1.0, 1.0], &mut z);
+
+        assert_eq!([0.5, 0.25, -2.0], z);
+        assert!(Jacobi::new(&[1.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn ilu0_dense() {
+        // Without any dropped fill, ILU(0) is the exact LU decomposition.
+        let a = Matrix::from_rows([[4.0, 1.0, 2.0], [1.0, 5.0, 1.0], [2.0, 1.0, 6.0]]);
+        let ilu = Ilu0::new(&CsrMatrix::from(a.clone())).unwrap();
+
+        let mut z = [0.0; 3];
+        ilu.apply(&[7.0, 7.0, 9.0], &mut z);
+        z.iter()
+            .for_each(|&z| assert!((1.0_f64 - z).abs() < 1e-12, "{}", z));
+    }
+
+    #[test]
+    fn ilu0_pattern() {
+        // A tridiagonal matrix has no fill, so its factors stay tridiagonal.
+        let a = CsrMatrix::from(Matrix::from_rows([
+            [2.0, -1.0, 0.0, 0.0],
+            [-1.0, 2.0, -1.0, 0.0],
+            [0.0, -1.0, 2.0, -1.0],
+            [0.0, 0.0, -1.0, 2.0],
+        ]));
+        let ilu = Ilu0::new(&a).unwrap();
+
+        assert_eq!(a.indices(), &ilu.lu.idx[..]);
+        assert!((1.25_f64 - ilu.lu.values[ilu.diag[3]]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ilu0_zero_pivot() {
+        let a = CsrMatrix::from(Matrix::from_rows([[0.0, 1.0], [1.0, 0.0]]));
+        assert!(Ilu0::new(&a).is_none());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::scalar::Scalar;
+
+pub mod cholesky;
+pub mod coo;
+pub mod csc;
+pub mod csr;
+pub mod lu;
+pub mod ordering;
+#[cfg(feature = "rayon")]
+mod par;
+
+/// Storage shared by the compressed formats: the entries of each major lane
+/// (rows for CSR, columns for CSC) are kept sorted by their minor index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Compressed<T> {
+    pub(crate) nmajor: usize,
+    pub(crate) nminor: usize,
+    pub(crate) ptr: Vec<usize>,
+    pub(crate) idx: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> Compressed<T> {
+    pub(crate) fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub(crate) fn lane(&self, major: usize) -> (&[usize], &[T]) {
+        let range = self.ptr[major]..self.ptr[major + 1];
+        (&self.idx[range.clone()], &self.values[range])
+    }
+
+    pub(crate) fn get(&self, major: usize, minor: usize) -> Option<&T> {
+        if major >= self.nmajor {
+            return None;
+        }
+
+        let (idx, values) = self.lane(major);
+        idx.binary_search(&minor).ok().map(|k| &values[k])
+    }
+
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.nmajor).flat_map(move |major| {
+            let (idx, values) = self.lane(major);
+            idx.iter()
+                .zip(values)
+                .map(move |(&minor, x)| (major, minor, x))
+        })
+    }
+
+    /// Swaps the roles of the major and minor axes.
+    pub(crate) fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut ptr = vec![0; self.nminor + 1];
+        for &minor in &self.idx {
+            ptr[minor + 1] += 1;
+        }
+        for k in 0..self.nminor {
+            ptr[k + 1] += ptr[k];
+        }
+
+        let mut next = ptr.clone();
+        let mut slots = vec![None; self.nnz()];
+        for (major, minor, x) in self.entries() {
+            slots[next[minor]] = Some((major, x.clone()));
+            next[minor] += 1;
+        }
+        let (idx, values) = slots
+            .into_iter()
+            .map(|slot| slot.expect("every entry is placed once"))
+            .unzip();
+
+        Self {
+            nmajor: self.nminor,
+            nminor: self.nmajor,
+            ptr,
+            idx,
+            values,
+        }
+    }
+}
+
+impl<T> Compressed<T>
+where
+    T: Scalar,
+{
+    /// Builds the storage from unordered entries, summing duplicates.
+    pub(crate) fn from_entries<I>(nmajor: usize, nminor: usize, entries: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        let mut lanes: Vec<Vec<(usize, T)>> = vec![Vec::new(); nmajor];
+        for (major, minor, x) in entries {
+            lanes[major].push((minor, x));
+        }
+
+        let mut ptr = Vec::with_capacity(nmajor + 1);
+        let mut idx = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        ptr.push(0);
+        for mut lane in lanes {
+            lane.sort_by_key(|&(minor, _)| minor);
+
+            let start = idx.len();
+            for (minor, x) in lane {
+                if idx.len() > start && idx.last() == Some(&minor) {
+                    let last = values.last_mut().expect("idx and values have equal length");
+                    *last = *last + x;
+                } else {
+                    idx.push(minor);
+                    values.push(x);
+                }
+            }
+            ptr.push(idx.len());
+        }
+
+        Self {
+            nmajor,
+            nminor,
+            ptr,
+            idx,
+            values,
+        }
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        Self::from_entries(
+            self.nmajor,
+            self.nminor,
+            self.entries()
+                .chain(rhs.entries())
+                .map(|(major, minor, &x)| (major, minor, x)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_entries() {
+        let storage = Compressed::from_entries(
+            3,
+            4,
+            vec![(2, 1, 5), (0, 3, 1), (0, 0, 2), (2, 1, -1), (2, 0, 7)],
+        );
+
+        assert_eq!(vec![0, 2, 2, 4], storage.ptr);
+        assert_eq!(vec![0, 3, 0, 1], storage.idx);
+        assert_eq!(vec![2, 1, 7, 4], storage.values);
+        assert_eq!(Some(&4), storage.get(2, 1));
+        assert_eq!(None, storage.get(1, 1));
+    }
+
+    #[test]
+    fn transpose() {
+        let storage = Compressed::from_entries(2, 3, vec![(0, 0, 1), (0, 2, 2), (1, 1, 3)]);
+        let transposed = storage.transpose();
+
+        assert_eq!((3, 2), (transposed.nmajor, transposed.nminor));
+        assert_eq!(vec![0, 1, 2, 3], transposed.ptr);
+        assert_eq!(vec![0, 1, 0], transposed.idx);
+        assert_eq!(vec![1, 3, 2], transposed.values);
+        assert_eq!(storage, transposed.transpose());
+    }
+}
@@ -0,0 +1,274 @@
+use super::{
+    csc::CscMatrix,
+    ordering::{invert, FillOrdering},
+    Compressed,
+};
+use crate::scalar::Real;
+
+const NONE: usize = usize::MAX;
+
+/// Sparse Cholesky decomposition of a symmetric positive-definite matrix,
+/// `P * A * Pᵀ = L * Lᵀ`, computed row by row along the elimination tree.
+#[derive(Debug, Clone)]
+pub struct SparseCholesky<T> {
+    l: CscMatrix<T>,
+    perm: Vec<usize>,
+}
+
+impl<T> SparseCholesky<T>
+where
+    T: Real,
+{
+    /// Returns `None` if `a` is not positive definite. Only the upper triangle
+    /// of the permuted matrix is read, so `a` is assumed to be symmetric.
+    pub fn new(a: &CscMatrix<T>, ordering: FillOrdering) -> Option<Self> {
+        let perm = ordering.permutation(&a.0);
+        let pinv = invert(&perm);
+        let n = perm.len();
+
+        let c = Compressed::from_entries(
+            n,
+            n,
+            a.entries()
+                .map(|(i, j, &x)| (pinv[j], pinv[i], x))
+                .filter(|&(j, i, _)| i <= j),
+        );
+        let parent = etree(&c);
+
+        let mut flag = vec![NONE; n];
+        let mut stack = vec![0; n];
+        let mut counts = vec![1; n];
+        for k in 0..n {
+            for &i in ereach(&c, k, &parent, &mut flag, &mut stack) {
+                counts[i] += 1;
+            }
+        }
+
+        let mut ptr = vec![0; n + 1];
+        for k in 0..n {
+            ptr[k + 1] = ptr[k] + counts[k];
+        }
+        let mut idx = vec![0; ptr[n]];
+        let mut values = vec![T::id_add(); ptr[n]];
+
+        let mut next = ptr.clone();
+        let mut x = vec![T::id_add(); n];
+        flag.fill(NONE);
+        for k in 0..n {
+            let (rows, cx) = c.lane(k);
+            for (&i, &v) in rows.iter().zip(cx) {
+                x[i] = x[i] + v;
+            }
+
+            let mut d = x[k];
+            x[k] = T::id_add();
+            for &i in ereach(&c, k, &parent, &mut flag, &mut stack) {
+                let lki = x[i] / values[ptr[i]];
+                x[i] = T::id_add();
+                for p in ptr[i] + 1..next[i] {
+                    x[idx[p]] = x[idx[p]] - values[p] * lki;
+                }
+                d = d - lki * lki;
+
+                idx[next[i]] = k;
+                values[next[i]] = lki;
+                next[i] += 1;
+            }
+
+            if d <= T::id_add() {
+                return None;
+            }
+            idx[next[k]] = k;
+            values[next[k]] = d.sqrt();
+            next[k] += 1;
+        }
+
+        Some(Self {
+            l: CscMatrix(Compressed {
+                nmajor: n,
+                nminor: n,
+                ptr,
+                idx,
+                values,
+            }),
+            perm,
+        })
+    }
+
+    /// Returns the lower-triangular factor of the permuted matrix.
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.perm.len();
+        assert_eq!(
+            n,
+            b.len(),
+            "cannot solve a {}x{} system with a right-hand side of length {}",
+            n,
+            n,
+            b.len()
+        );
+
+        let Compressed {
+            ptr, idx, values, ..
+        } = &self.l.0;
+        let mut x: Vec<T> = self.perm.iter().map(|&i| b[i]).collect();
+
+        for j in 0..n {
+            x[j] = x[j] / values[ptr[j]];
+            for p in ptr[j] + 1..ptr[j + 1] {
+                x[idx[p]] = x[idx[p]] - values[p] * x[j];
+            }
+        }
+        for j in (0..n).rev() {
+            for p in ptr[j] + 1..ptr[j + 1] {
+                x[j] = x[j] - values[p] * x[idx[p]];
+            }
+            x[j] = x[j] / values[ptr[j]];
+        }
+
+        let mut y = vec![T::id_add(); n];
+        for (k, &i) in self.perm.iter().enumerate() {
+            y[i] = x[k];
+        }
+
+        y
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Real,
+{
+    pub fn cholesky(&self, ordering: FillOrdering) -> Option<SparseCholesky<T>> {
+        SparseCholesky::new(self, ordering)
+    }
+}
+
+/// Computes the elimination tree of the matrix whose upper triangle is `c`.
+fn etree<T>(c: &Compressed<T>) -> Vec<usize> {
+    let n = c.nmajor;
+    let mut parent = vec![NONE; n];
+    let mut ancestor = vec![NONE; n];
+
+    for k in 0..n {
+        for &i in c.lane(k).0 {
+            let mut i = i;
+            while i != NONE && i < k {
+                let next = ancestor[i];
+                ancestor[i] = k;
+                if next == NONE {
+                    parent[i] = k;
+                }
+                i = next;
+            }
+        }
+    }
+
+    parent
+}
+
+/// Finds the nonzero pattern of row `k` of `L`, excluding the diagonal, in
+/// topological order.
+fn ereach<'a, T>(
+    c: &Compressed<T>,
+    k: usize,
+    parent: &[usize],
+    flag: &mut [usize],
+    stack: &'a mut [usize],
+) -> &'a [usize] {
+    let n = c.nmajor;
+    let mut top = n;
+    flag[k] = k;
+
+    for &i in c.lane(k).0 {
+        let mut i = i;
+        let mut len = 0;
+        while flag[i] != k {
+            stack[len] = i;
+            len += 1;
+            flag[i] = k;
+            i = parent[i];
+        }
+        while len > 0 {
+            len -= 1;
+            top -= 1;
+            stack.swap(top, len);
+        }
+    }
+
+    &stack[top..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix::Matrix, sparse::coo::CooMatrix, testing::poisson};
+
+    #[test]
+    fn solve() {
+        let a = CscMatrix::from(poisson(12));
+        let expected: Vec<f64> = (0..144).map(|i| (i as f64).sin()).collect();
+        let b = a.mul_vec(&expected);
+
+        for ordering in [FillOrdering::Natural, FillOrdering::MinimumDegree] {
+            let x = a.cholesky(ordering).unwrap().solve(&b);
+            x.iter()
+                .zip(&expected)
+                .for_each(|(x, y)| assert!((x - y).abs() < 1e-10));
+        }
+    }
+
+    #[test]
+    fn factor() {
+        let a = CscMatrix::from(Matrix::from_rows([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]));
+        let chol = a.cholesky(FillOrdering::Natural).unwrap();
+
+        assert_eq!(
+            Ok(Matrix::from_rows([
+                [2.0, 0.0, 0.0],
+                [6.0, 1.0, 0.0],
+                [-8.0, 5.0, 3.0]
+            ])),
+            Matrix::try_from(chol.l().clone())
+        );
+    }
+
+    #[test]
+    fn fill_in() {
+        let n = 50;
+        let mut coo = CooMatrix::new(n, n);
+        coo.push(0, 0, n as f64);
+        for i in 1..n {
+            coo.push(i, i, 2.0);
+            coo.push(0, i, 1.0);
+            coo.push(i, 0, 1.0);
+        }
+        let a = CscMatrix::from(coo);
+
+        assert_eq!(
+            n * (n + 1) / 2,
+            a.cholesky(FillOrdering::Natural).unwrap().l().nnz()
+        );
+        assert_eq!(
+            2 * n - 1,
+            a.cholesky(FillOrdering::MinimumDegree).unwrap().l().nnz()
+        );
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let a = CscMatrix::from(Matrix::from_rows([[1.0, 2.0], [2.0, 1.0]]));
+        assert!(a.cholesky(FillOrdering::MinimumDegree).is_none());
+    }
+}
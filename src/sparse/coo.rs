@@ -0,0 +1,141 @@
+use super::{csc::CscMatrix, csr::CsrMatrix};
+
+/// Sparse matrix in coordinate format, meant for assembling entries in any
+/// order before converting to [`CsrMatrix`] or [`CscMatrix`]. Duplicate
+/// entries are summed on conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CooMatrix<T> {
+    nrows: usize,
+    ncols: usize,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        Self {
+            nrows,
+            ncols,
+            rows: Vec::new(),
+            cols: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        assert!(
+            i < self.nrows && j < self.ncols,
+            "index out of bounds: the len is ({}, {}) but the index is ({}, {})",
+            self.nrows,
+            self.ncols,
+            i,
+            j
+        );
+
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    /// Counts the stored triplets, including duplicates.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn triplets(&self) -> impl ExactSizeIterator<Item = (usize, usize, &T)> {
+        self.rows
+            .iter()
+            .zip(&self.cols)
+            .zip(&self.values)
+            .map(|((&i, &j), x)| (i, j, x))
+    }
+
+    pub(crate) fn into_triplets(self) -> impl Iterator<Item = (usize, usize, T)> {
+        self.rows
+            .into_iter()
+            .zip(self.cols)
+            .zip(self.values)
+            .map(|((i, j), x)| (i, j, x))
+    }
+}
+
+impl<T> From<&CsrMatrix<T>> for CooMatrix<T>
+where
+    T: Clone,
+{
+    fn from(value: &CsrMatrix<T>) -> Self {
+        let mut coo = Self::new(value.nrows(), value.ncols());
+        for (i, j, x) in value.entries() {
+            coo.push(i, j, x.clone());
+        }
+
+        coo
+    }
+}
+
+impl<T> From<&CscMatrix<T>> for CooMatrix<T>
+where
+    T: Clone,
+{
+    fn from(value: &CscMatrix<T>) -> Self {
+        let mut coo = Self::new(value.nrows(), value.ncols());
+        for (i, j, x) in value.entries() {
+            coo.push(i, j, x.clone());
+        }
+
+        coo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut coo = CooMatrix::new(2, 3);
+        coo.push(1, 2, 5);
+        coo.push(0, 0, 1);
+        coo.push(1, 2, 2);
+
+        assert_eq!(3, coo.nnz());
+        assert_eq!(
+            vec![(1, 2, &5), (0, 0, &1), (1, 2, &2)],
+            coo.triplets().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is (2, 3) but the index is (2, 0)")]
+    fn push_out_of_bounds() {
+        CooMatrix::new(2, 3).push(2, 0, 1);
+    }
+
+    #[test]
+    fn from_csr() {
+        let mut coo = CooMatrix::new(2, 3);
+        coo.push(1, 2, 5);
+        coo.push(0, 1, 1);
+        coo.push(1, 2, 2);
+
+        let mut expected = CooMatrix::new(2, 3);
+        expected.push(0, 1, 1);
+        expected.push(1, 2, 7);
+
+        assert_eq!(expected, CooMatrix::from(&CsrMatrix::from(coo.clone())));
+        assert_eq!(expected, CooMatrix::from(&CscMatrix::from(coo)));
+    }
+}
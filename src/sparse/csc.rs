@@ -0,0 +1,239 @@
+use super::{coo::CooMatrix, csr::CsrMatrix, Compressed};
+use crate::{
+    dmatrix::{DMatrix, ShapeError},
+    matrix::Matrix,
+    scalar::Scalar,
+};
+use std::ops::Add;
+
+/// Sparse matrix in compressed sparse column format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CscMatrix<T>(pub(crate) Compressed<T>);
+
+impl<T> CscMatrix<T> {
+    pub fn nrows(&self) -> usize {
+        self.0.nminor
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.0.nmajor
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.0.nnz()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.0.ptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.0.idx
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.0.values
+    }
+
+    /// Returns the row indices and values stored in column `j`.
+    pub fn col(&self, j: usize) -> Option<(&[usize], &[T])> {
+        (j < self.ncols()).then(|| self.0.lane(j))
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.0.get(j, i)
+    }
+
+    /// Iterates over the stored entries in column-major order.
+    pub fn entries(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.0.entries().map(|(j, i, x)| (i, j, x))
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.transpose())
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Scalar,
+{
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(
+            self.ncols(),
+            x.len(),
+            "cannot multiply a {}x{} matrix by a vector of length {}",
+            self.nrows(),
+            self.ncols(),
+            x.len()
+        );
+
+        let mut y = vec![T::id_add(); self.nrows()];
+        for (i, j, &v) in self.entries() {
+            y[i] = y[i] + v * x[j];
+        }
+
+        y
+    }
+}
+
+impl<T> Add for CscMatrix<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.shape() == rhs.shape(),
+            "cannot add a {}x{} matrix and a {}x{} matrix",
+            self.nrows(),
+            self.ncols(),
+            rhs.nrows(),
+            rhs.ncols()
+        );
+
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+impl<T> From<CooMatrix<T>> for CscMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: CooMatrix<T>) -> Self {
+        let (nrows, ncols) = value.shape();
+        Self(Compressed::from_entries(
+            ncols,
+            nrows,
+            value.into_triplets().map(|(i, j, x)| (j, i, x)),
+        ))
+    }
+}
+
+impl<T> From<&CsrMatrix<T>> for CscMatrix<T>
+where
+    T: Clone,
+{
+    fn from(value: &CsrMatrix<T>) -> Self {
+        Self(value.0.transpose())
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for CscMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: Matrix<T, M, N>) -> Self {
+        Self::from(&CsrMatrix::from(value))
+    }
+}
+
+impl<T> From<DMatrix<T>> for CscMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: DMatrix<T>) -> Self {
+        Self::from(&CsrMatrix::from(value))
+    }
+}
+
+impl<T> From<CscMatrix<T>> for DMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: CscMatrix<T>) -> Self {
+        let mut dense = DMatrix::id_add(value.nrows(), value.ncols());
+        for (i, j, &x) in value.entries() {
+            dense[(i, j)] = x;
+        }
+
+        dense
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<CscMatrix<T>> for Matrix<T, M, N>
+where
+    T: Scalar,
+{
+    type Error = ShapeError;
+
+    fn try_from(value: CscMatrix<T>) -> Result<Self, Self::Error> {
+        DMatrix::from(value).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CscMatrix<i32> {
+        let mut coo = CooMatrix::new(3, 4);
+        coo.push(0, 0, 1);
+        coo.push(2, 3, 4);
+        coo.push(0, 2, 2);
+        coo.push(2, 1, 3);
+        coo.push(2, 3, 1);
+
+        CscMatrix::from(coo)
+    }
+
+    #[test]
+    fn from_coo() {
+        let csc = sample();
+
+        assert_eq!((3, 4), csc.shape());
+        assert_eq!(4, csc.nnz());
+        assert_eq!(&[0, 1, 2, 3, 4], csc.indptr());
+        assert_eq!(&[0, 2, 0, 2], csc.indices());
+        assert_eq!(&[1, 3, 2, 5], csc.values());
+        assert_eq!(Some(&5), csc.get(2, 3));
+        assert_eq!(None, csc.get(1, 0));
+        assert_eq!(Some((&[2][..], &[3][..])), csc.col(1));
+    }
+
+    #[test]
+    fn dense() {
+        let mat = Matrix::from_rows([[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 5]]);
+
+        assert_eq!(sample(), CscMatrix::from(mat.clone()));
+        assert_eq!(Ok(mat), Matrix::try_from(sample()));
+    }
+
+    #[test]
+    fn transpose() {
+        let mat = Matrix::from_rows([[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 5]]);
+
+        assert_eq!(Ok(mat.transpose()), Matrix::try_from(sample().transpose()));
+    }
+
+    #[test]
+    fn mul_vec() {
+        assert_eq!(vec![7, 0, 26], sample().mul_vec(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn add() {
+        let other = CscMatrix::from(Matrix::from_rows([
+            [-1, 1, 0, 0],
+            [0, 0, 0, 2],
+            [0, 0, 0, 0],
+        ]));
+
+        assert_eq!(
+            Ok(Matrix::from_rows([
+                [0, 1, 2, 0],
+                [0, 0, 0, 2],
+                [0, 3, 0, 5]
+            ])),
+            Matrix::try_from(sample() + other)
+        );
+    }
+}
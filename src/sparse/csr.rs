@@ -0,0 +1,266 @@
+use super::{coo::CooMatrix, csc::CscMatrix, Compressed};
+use crate::{
+    dmatrix::{DMatrix, ShapeError},
+    matrix::Matrix,
+    scalar::Scalar,
+};
+use std::ops::Add;
+
+/// Sparse matrix in compressed sparse row format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrMatrix<T>(pub(crate) Compressed<T>);
+
+impl<T> CsrMatrix<T> {
+    pub fn nrows(&self) -> usize {
+        self.0.nmajor
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.0.nminor
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.0.nnz()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.0.ptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.0.idx
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.0.values
+    }
+
+    /// Returns the column indices and values stored in row `i`.
+    pub fn row(&self, i: usize) -> Option<(&[usize], &[T])> {
+        (i < self.nrows()).then(|| self.0.lane(i))
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.0.get(i, j)
+    }
+
+    /// Iterates over the stored entries in row-major order.
+    pub fn entries(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.0.entries()
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self(self.0.transpose())
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Scalar,
+{
+    pub fn mul_vec(&self, x: &[T]) -> Vec<T> {
+        self.check_len(x);
+
+        (0..self.nrows()).map(|i| self.row_dot(i, x)).collect()
+    }
+
+    pub(super) fn check_len(&self, x: &[T]) {
+        assert_eq!(
+            self.ncols(),
+            x.len(),
+            "cannot multiply a {}x{} matrix by a vector of length {}",
+            self.nrows(),
+            self.ncols(),
+            x.len()
+        );
+    }
+
+    /// Returns the dot product of row `i` with `x`.
+    pub(super) fn row_dot(&self, i: usize, x: &[T]) -> T {
+        let (idx, values) = self.0.lane(i);
+        idx.iter()
+            .zip(values)
+            .fold(T::id_add(), |sum, (&j, &v)| sum + v * x[j])
+    }
+}
+
+impl<T> Add for CsrMatrix<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.shape() == rhs.shape(),
+            "cannot add a {}x{} matrix and a {}x{} matrix",
+            self.nrows(),
+            self.ncols(),
+            rhs.nrows(),
+            rhs.ncols()
+        );
+
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+impl<T> From<CooMatrix<T>> for CsrMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: CooMatrix<T>) -> Self {
+        let (nrows, ncols) = value.shape();
+        Self(Compressed::from_entries(
+            nrows,
+            ncols,
+            value.into_triplets(),
+        ))
+    }
+}
+
+impl<T> From<&CscMatrix<T>> for CsrMatrix<T>
+where
+    T: Clone,
+{
+    fn from(value: &CscMatrix<T>) -> Self {
+        Self(value.0.transpose())
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for CsrMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: Matrix<T, M, N>) -> Self {
+        Self::from(DMatrix::from(value))
+    }
+}
+
+impl<T> From<DMatrix<T>> for CsrMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: DMatrix<T>) -> Self {
+        let (nrows, ncols) = value.shape();
+        Self(Compressed::from_entries(
+            nrows,
+            ncols,
+            value
+                .cursor()
+                .filter(|&(_, _, &x)| x != T::id_add())
+                .map(|(i, j, &x)| (i, j, x)),
+        ))
+    }
+}
+
+impl<T> From<CsrMatrix<T>> for DMatrix<T>
+where
+    T: Scalar,
+{
+    fn from(value: CsrMatrix<T>) -> Self {
+        let mut dense = DMatrix::id_add(value.nrows(), value.ncols());
+        for (i, j, &x) in value.entries() {
+            dense[(i, j)] = x;
+        }
+
+        dense
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<CsrMatrix<T>> for Matrix<T, M, N>
+where
+    T: Scalar,
+{
+    type Error = ShapeError;
+
+    fn try_from(value: CsrMatrix<T>) -> Result<Self, Self::Error> {
+        DMatrix::from(value).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CsrMatrix<i32> {
+        let mut coo = CooMatrix::new(3, 4);
+        coo.push(0, 0, 1);
+        coo.push(2, 3, 4);
+        coo.push(0, 2, 2);
+        coo.push(2, 1, 3);
+        coo.push(2, 3, 1);
+
+        CsrMatrix::from(coo)
+    }
+
+    #[test]
+    fn from_coo() {
+        let csr = sample();
+
+        assert_eq!((3, 4), csr.shape());
+        assert_eq!(4, csr.nnz());
+        assert_eq!(&[0, 2, 2, 4], csr.indptr());
+        assert_eq!(&[0, 2, 1, 3], csr.indices());
+        assert_eq!(&[1, 2, 3, 5], csr.values());
+        assert_eq!(Some(&5), csr.get(2, 3));
+        assert_eq!(None, csr.get(1, 0));
+        assert_eq!(Some((&[][..], &[][..])), csr.row(1));
+    }
+
+    #[test]
+    fn dense() {
+        let mat = Matrix::from_rows([[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 5]]);
+
+        assert_eq!(sample(), CsrMatrix::from(mat.clone()));
+        assert_eq!(Ok(mat), Matrix::try_from(sample()));
+        assert_eq!(
+            DMatrix::from_rows(vec![[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 5]]),
+            DMatrix::from(sample())
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let mat = Matrix::from_rows([[1, 0, 2, 0], [0, 0, 0, 0], [0, 3, 0, 5]]);
+
+        assert_eq!(Ok(mat.transpose()), Matrix::try_from(sample().transpose()));
+    }
+
+    #[test]
+    fn mul_vec() {
+        assert_eq!(vec![7, 0, 26], sample().mul_vec(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn add() {
+        let other = CsrMatrix::from(Matrix::from_rows([
+            [-1, 1, 0, 0],
+            [0, 0, 0, 2],
+            [0, 0, 0, 0],
+        ]));
+
+        assert_eq!(
+            Ok(Matrix::from_rows([
+                [0, 1, 2, 0],
+                [0, 0, 0, 2],
+                [0, 3, 0, 5]
+            ])),
+            Matrix::try_from(sample() + other)
+        );
+    }
+
+    #[test]
+    fn to_csc() {
+        let csc = CscMatrix::from(&sample());
+
+        assert_eq!(&[0, 1, 2, 3, 4], csc.indptr());
+        assert_eq!(sample(), CsrMatrix::from(&csc));
+    }
+}
@@ -0,0 +1,333 @@
+use super::{csc::CscMatrix, ordering::FillOrdering, Compressed};
+use crate::{matrix::solve::SolveError, scalar::Real};
+
+const NONE: usize = usize::MAX;
+
+/// Sparse LU decomposition with partial pivoting, `P * A * Q = L * U`,
+/// computed column by column with sparse triangular solves.
+#[derive(Debug, Clone)]
+pub struct SparseLu<T> {
+    l: CscMatrix<T>,
+    u: CscMatrix<T>,
+    pinv: Vec<usize>,
+    q: Vec<usize>,
+}
+
+impl<T> SparseLu<T>
+where
+    T: Real,
+{
+    pub fn new(a: &CscMatrix<T>, ordering: FillOrdering) -> Result<Self, SolveError> {
+        let q = ordering.permutation(&a.0);
+        let n = q.len();
+
+        let mut l = Compressed {
+            nmajor: 0,
+            nminor: n,
+            ptr: vec![0],
+            idx: Vec::new(),
+            values: Vec::new(),
+        };
+        let mut u = l.clone();
+
+        let mut pinv = vec![NONE; n];
+        let mut x = vec![T::id_add(); n];
+        let mut reach = Reach::new(n);
+
+        for &col in &q {
+            let k = l.nmajor;
+            let top = reach.solve(&l, &a.0, col, &pinv, &mut x);
+
+            let mut ipiv = NONE;
+            let mut max = T::id_add();
+            for &i in &reach.xi[top..] {
+                if pinv[i] == NONE {
+                    if x[i].abs() > max {
+                        max = x[i].abs();
+                        ipiv = i;
+                    }
+                } else {
+                    u.idx.push(pinv[i]);
+                    u.values.push(x[i]);
+                }
+            }
+            if ipiv == NONE {
+                return Err(SolveError::Singular);
+            }
+
+            let pivot = x[ipiv];
+            u.idx.push(k);
+            u.values.push(pivot);
+            pinv[ipiv] = k;
+
+            l.idx.push(ipiv);
+            l.values.push(T::id_mul());
+            for &i in &reach.xi[top..] {
+                if pinv[i] == NONE {
+                    l.idx.push(i);
+                    l.values.push(x[i] / pivot);
+                }
+                x[i] = T::id_add();
+            }
+
+            l.nmajor += 1;
+            l.ptr.push(l.idx.len());
+            u.nmajor += 1;
+            u.ptr.push(u.idx.len());
+        }
+
+        for i in &mut l.idx {
+            *i = pinv[*i];
+        }
+        sort_lanes(&mut l);
+        sort_lanes(&mut u);
+
+        Ok(Self {
+            l: CscMatrix(l),
+            u: CscMatrix(u),
+            pinv,
+            q,
+        })
+    }
+
+    /// Returns the unit lower-triangular factor.
+    pub fn l(&self) -> &CscMatrix<T> {
+        &self.l
+    }
+
+    pub fn u(&self) -> &CscMatrix<T> {
+        &self.u
+    }
+
+    /// Returns the row permutation, where row `i` of `A` becomes row `p()[i]` of `P * A`.
+    pub fn p(&self) -> &[usize] {
+        &self.pinv
+    }
+
+    /// Returns the column permutation, where column `k` of `A * Q` is column `q()[k]` of `A`.
+    pub fn q(&self) -> &[usize] {
+        &self.q
+    }
+
+    pub fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.q.len();
+        assert_eq!(
+            n,
+            b.len(),
+            "cannot solve a {}x{} system with a right-hand side of length {}",
+            n,
+            n,
+            b.len()
+        );
+
+        let mut x = vec![T::id_add(); n];
+        for (i, &k) in self.pinv.iter().enumerate() {
+            x[k] = b[i];
+        }
+
+        let l = &self.l.0;
+        for j in 0..n {
+            for p in l.ptr[j] + 1..l.ptr[j + 1] {
+                x[l.idx[p]] = x[l.idx[p]] - l.values[p] * x[j];
+            }
+        }
+
+        let u = &self.u.0;
+        for j in (0..n).rev() {
+            let diag = u.ptr[j + 1] - 1;
+            x[j] = x[j] / u.values[diag];
+            for p in u.ptr[j]..diag {
+                x[u.idx[p]] = x[u.idx[p]] - u.values[p] * x[j];
+            }
+        }
+
+        let mut y = vec![T::id_add(); n];
+        for (k, &j) in self.q.iter().enumerate() {
+            y[j] = x[k];
+        }
+
+        y
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Real,
+{
+    pub fn lu(&self, ordering: FillOrdering) -> Result<SparseLu<T>, SolveError> {
+        SparseLu::new(self, ordering)
+    }
+}
+
+/// Scratch space for computing which rows a sparse triangular solve touches.
+struct Reach {
+    xi: Vec<usize>,
+    stack: Vec<usize>,
+    cursor: Vec<usize>,
+    marked: Vec<bool>,
+}
+
+impl Reach {
+    fn new(n: usize) -> Self {
+        Self {
+            xi: vec![0; n],
+            stack: Vec::with_capacity(n),
+            cursor: vec![0; n],
+            marked: vec![false; n],
+        }
+    }
+
+    /// Solves `L * x = A[:, col]` for the partial `L`, leaving the nonzero rows
+    /// of `x` in `xi[top..]` in topological order and returning `top`.
+    fn solve<T>(
+        &mut self,
+        l: &Compressed<T>,
+        a: &Compressed<T>,
+        col: usize,
+        pinv: &[usize],
+        x: &mut [T],
+    ) -> usize
+    where
+        T: Real,
+    {
+        let (rows, values) = a.lane(col);
+
+        let mut top = self.xi.len();
+        for &i in rows {
+            if !self.marked[i] {
+                top = self.dfs(i, l, pinv, top);
+            }
+        }
+        for &i in &self.xi[top..] {
+            self.marked[i] = false;
+        }
+
+        for &i in &self.xi[top..] {
+            x[i] = T::id_add();
+        }
+        for (&i, &v) in rows.iter().zip(values) {
+            x[i] = v;
+        }
+
+        for &i in &self.xi[top..] {
+            let j = pinv[i];
+            if j == NONE {
+                continue;
+            }
+            for p in l.ptr[j] + 1..l.ptr[j + 1] {
+                x[l.idx[p]] = x[l.idx[p]] - l.values[p] * x[i];
+            }
+        }
+
+        top
+    }
+
+    fn dfs<T>(&mut self, start: usize, l: &Compressed<T>, pinv: &[usize], mut top: usize) -> usize {
+        self.stack.push(start);
+
+        while let Some(&i) = self.stack.last() {
+            let j = pinv[i];
+            if !self.marked[i] {
+                self.marked[i] = true;
+                self.cursor[i] = if j == NONE { 0 } else { l.ptr[j] };
+            }
+
+            let end = if j == NONE { 0 } else { l.ptr[j + 1] };
+            let next = (self.cursor[i]..end).find(|&p| !self.marked[l.idx[p]]);
+            match next {
+                Some(p) => {
+                    self.cursor[i] = p + 1;
+                    self.stack.push(l.idx[p]);
+                }
+                None => {
+                    self.stack.pop();
+                    top -= 1;
+                    self.xi[top] = i;
+                }
+            }
+        }
+
+        top
+    }
+}
+
+fn sort_lanes<T>(a: &mut Compressed<T>)
+where
+    T: Copy,
+{
+    for k in 0..a.nmajor {
+        let range = a.ptr[k]..a.ptr[k + 1];
+        let mut lane: Vec<(usize, T)> = a.idx[range.clone()]
+            .iter()
+            .copied()
+            .zip(a.values[range.clone()].iter().copied())
+            .collect();
+        lane.sort_by_key(|&(i, _)| i);
+
+        for (p, (i, x)) in range.zip(lane) {
+            a.idx[p] = i;
+            a.values[p] = x;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix::Matrix, testing::convection_diffusion};
+
+    #[test]
+    fn factors() {
+        let mat = Matrix::from_rows([
+            [0.0, 2.0, 0.0, 1.0],
+            [3.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 4.0, 0.0],
+            [1.0, 0.0, 2.0, 5.0],
+        ]);
+        let lu = CscMatrix::from(mat.clone())
+            .lu(FillOrdering::MinimumDegree)
+            .unwrap();
+
+        let l = Matrix::<f64, 4, 4>::try_from(lu.l().clone()).unwrap();
+        let u = Matrix::<f64, 4, 4>::try_from(lu.u().clone()).unwrap();
+        let pa_q: Matrix<f64, 4, 4> = Matrix::from_rows(std::array::from_fn(|k| {
+            let i = lu.p().iter().position(|&p| p == k).unwrap();
+            std::array::from_fn(|c| mat[(i, lu.q()[c])])
+        }));
+
+        (l * u)
+            .iter()
+            .zip(pa_q.iter())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-12));
+    }
+
+    #[test]
+    fn solve() {
+        // A convection-diffusion stencil, which is not symmetric.
+        let a = CscMatrix::from(convection_diffusion(10));
+        let n = a.nrows();
+        let expected: Vec<f64> = (0..n).map(|i| (i as f64).cos()).collect();
+        let b = a.mul_vec(&expected);
+
+        for ordering in [FillOrdering::Natural, FillOrdering::MinimumDegree] {
+            let x = a.lu(ordering).unwrap().solve(&b);
+            x.iter()
+                .zip(&expected)
+                .for_each(|(x, y)| assert!((x - y).abs() < 1e-10));
+        }
+    }
+
+    #[test]
+    fn singular() {
+        let a = CscMatrix::from(Matrix::from_rows([
+            [1.0, 2.0, 0.0],
+            [2.0, 4.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]));
+
+        assert_eq!(
+            Some(SolveError::Singular),
+            a.lu(FillOrdering::Natural).err()
+        );
+    }
+}
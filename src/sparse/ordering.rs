@@ -0,0 +1,280 @@
+use super::Compressed;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    mem,
+};
+
+/// Symmetric permutation applied before a sparse factorization to limit fill-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillOrdering {
+    /// Keeps the original order.
+    Natural,
+    /// Approximate minimum degree (Amestoy, Davis and Duff) on the graph of
+    /// `A + Aᵀ`, which picks nearly the same pivots as exact minimum degree
+    /// without ever forming the filled graph.
+    #[default]
+    MinimumDegree,
+}
+
+impl FillOrdering {
+    /// Returns `perm` such that `perm[k]` is the original index eliminated at step `k`.
+    pub(crate) fn permutation<T>(self, a: &Compressed<T>) -> Vec<usize> {
+        assert_eq!(
+            a.nmajor, a.nminor,
+            "cannot order a non-square {}x{} matrix",
+            a.nminor, a.nmajor
+        );
+
+        match self {
+            Self::Natural => (0..a.nmajor).collect(),
+            Self::MinimumDegree => Amd::new(a).order(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Node {
+    /// A supervariable that is yet to be eliminated.
+    Variable,
+    /// An eliminated pivot, standing in for the clique it created.
+    Element,
+    /// An element absorbed by a later one, or a variable merged into an
+    /// indistinguishable supervariable.
+    Absorbed,
+}
+
+/// Quotient graph in which eliminated pivots are kept as elements instead of
+/// being expanded into cliques, so that the graph never grows.
+struct Amd {
+    node: Vec<Node>,
+    /// Variables adjacent to each variable through an original entry.
+    vars: Vec<Vec<usize>>,
+    /// Elements adjacent to each variable.
+    elems: Vec<Vec<usize>>,
+    /// Variables adjacent to each element.
+    lme: Vec<Vec<usize>>,
+    /// Number of original variables in each supervariable.
+    weight: Vec<usize>,
+    /// Original variables in each supervariable, in elimination order.
+    members: Vec<Vec<usize>>,
+    /// Upper bound on the external degree of each variable.
+    degree: Vec<usize>,
+}
+
+impl Amd {
+    fn new<T>(a: &Compressed<T>) -> Self {
+        let n = a.nmajor;
+        let mut vars = vec![Vec::new(); n];
+        for (major, minor, _) in a.entries() {
+            if major != minor {
+                vars[major].push(minor);
+                vars[minor].push(major);
+            }
+        }
+        for v in &mut vars {
+            v.sort_unstable();
+            v.dedup();
+        }
+
+        Self {
+            node: vec![Node::Variable; n],
+            degree: vars.iter().map(Vec::len).collect(),
+            vars,
+            elems: vec![Vec::new(); n],
+            lme: vec![Vec::new(); n],
+            weight: vec![1; n],
+            members: (0..n).map(|i| vec![i]).collect(),
+        }
+    }
+
+    fn order(mut self) -> Vec<usize> {
+        let n = self.node.len();
+        let mut heap: BinaryHeap<_> = (0..n).map(|i| Reverse((self.degree[i], i))).collect();
+        let mut remaining = n;
+        let mut in_lp = vec![false; n];
+        // `|L_e \ L_p|` for the elements touched by the current pivot.
+        let mut outside: Vec<Option<usize>> = vec![None; n];
+        let mut perm = Vec::with_capacity(n);
+
+        while let Some(Reverse((degree, p))) = heap.pop() {
+            if self.node[p] != Node::Variable || degree != self.degree[p] {
+                continue;
+            }
+            perm.append(&mut self.members[p]);
+            remaining -= self.weight[p];
+
+            // The new element `p` reaches every variable that `p` or one of
+            // its elements did, and absorbs those elements.
+            let mut reach = mem::take(&mut self.vars[p]);
+            for e in mem::take(&mut self.elems[p]) {
+                if self.node[e] == Node::Element {
+                    self.node[e] = Node::Absorbed;
+                    reach.append(&mut self.lme[e]);
+                }
+            }
+
+            let mut lp = Vec::new();
+            for i in reach {
+                if self.node[i] == Node::Variable && i != p && !in_lp[i] {
+                    in_lp[i] = true;
+                    lp.push(i);
+                }
+            }
+            self.node[p] = Node::Element;
+            let lp_weight: usize = lp.iter().map(|&i| self.weight[i]).sum();
+
+            for &i in &lp {
+                let node = &self.node;
+                self.elems[i].retain(|&e| node[e] == Node::Element && e != p);
+                self.elems[i].push(p);
+                self.vars[i].retain(|&j| node[j] == Node::Variable && !in_lp[j]);
+            }
+
+            // Subtract each variable of `L_p` from the elements it belongs to,
+            // leaving how much of every such element lies outside `L_p`.
+            let mut touched = Vec::new();
+            for &i in &lp {
+                for &e in &self.elems[i] {
+                    if e == p {
+                        continue;
+                    }
+                    let w = outside[e].get_or_insert_with(|| {
+                        touched.push(e);
+                        let node = &self.node;
+                        self.lme[e].retain(|&j| node[j] == Node::Variable);
+                        self.lme[e].iter().map(|&j| self.weight[j]).sum()
+                    });
+                    *w -= self.weight[i];
+                }
+            }
+
+            // Elements lying entirely within `L_p` are redundant.
+            for &e in &touched {
+                if outside[e] == Some(0) {
+                    self.node[e] = Node::Absorbed;
+                    self.lme[e].clear();
+                }
+            }
+
+            for &i in &lp {
+                let node = &self.node;
+                self.elems[i].retain(|&e| node[e] == Node::Element);
+
+                let external = lp_weight - self.weight[i];
+                let bound = self.vars[i].iter().map(|&j| self.weight[j]).sum::<usize>()
+                    + external
+                    + self.elems[i]
+                        .iter()
+                        .filter(|&&e| e != p)
+                        .map(|&e| outside[e].unwrap_or(0))
+                        .sum::<usize>();
+                self.degree[i] = bound
+                    .min(self.degree[i] + external)
+                    .min(remaining - self.weight[i]);
+            }
+            for e in touched {
+                outside[e] = None;
+            }
+
+            self.merge_indistinguishable(&lp);
+
+            for &i in &lp {
+                in_lp[i] = false;
+                if self.node[i] == Node::Variable {
+                    heap.push(Reverse((self.degree[i], i)));
+                }
+            }
+            self.lme[p] = lp;
+        }
+
+        perm
+    }
+
+    /// Merges variables of `L_p` with identical adjacency into one
+    /// supervariable, so that they are eliminated together.
+    fn merge_indistinguishable(&mut self, lp: &[usize]) {
+        let mut buckets: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &i in lp {
+            self.vars[i].sort_unstable();
+            self.elems[i].sort_unstable();
+            let hash = self.vars[i].iter().chain(&self.elems[i]).sum();
+            buckets.entry(hash).or_default().push(i);
+        }
+
+        for bucket in buckets.into_values() {
+            for (k, &i) in bucket.iter().enumerate() {
+                if self.node[i] != Node::Variable {
+                    continue;
+                }
+                for &j in &bucket[k + 1..] {
+                    if self.node[j] == Node::Variable
+                        && self.vars[i] == self.vars[j]
+                        && self.elems[i] == self.elems[j]
+                    {
+                        self.node[j] = Node::Absorbed;
+                        self.degree[i] = self.degree[i].saturating_sub(self.weight[j]);
+                        self.weight[i] += mem::take(&mut self.weight[j]);
+                        let mut members = mem::take(&mut self.members[j]);
+                        self.members[i].append(&mut members);
+                        self.vars[j].clear();
+                        self.elems[j].clear();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Inverts a permutation.
+pub(crate) fn invert(perm: &[usize]) -> Vec<usize> {
+    let mut pinv = vec![0; perm.len()];
+    for (k, &i) in perm.iter().enumerate() {
+        pinv[i] = k;
+    }
+
+    pinv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sparse::csc::CscMatrix, testing::poisson};
+
+    #[test]
+    fn natural() {
+        let a = Compressed::from_entries(3, 3, vec![(0, 0, 1), (1, 1, 1), (2, 2, 1)]);
+        assert_eq!(vec![0, 1, 2], FillOrdering::Natural.permutation(&a));
+    }
+
+    #[test]
+    fn minimum_degree() {
+        // An arrowhead whose hub comes first fills in completely unless the hub
+        // is eliminated once at most one spoke remains.
+        let mut entries = vec![(0, 0, 1)];
+        for i in 1..5 {
+            entries.extend([(0, i, 1), (i, 0, 1), (i, i, 1)]);
+        }
+        let a = Compressed::from_entries(5, 5, entries);
+
+        let perm = FillOrdering::MinimumDegree.permutation(&a);
+        assert!(invert(&perm)[0] >= 3);
+    }
+
+    #[test]
+    fn poisson_fill() {
+        // The natural order of a 100x100 grid fills the whole band, which is
+        // about a million entries of `L`.
+        let size = 100;
+        let a = CscMatrix::from(poisson(size));
+
+        let perm = FillOrdering::MinimumDegree.permutation(&a.0);
+
+        let mut sorted = perm.clone();
+        sorted.sort_unstable();
+        assert_eq!((0..size * size).collect::<Vec<_>>(), sorted);
+
+        let nnz = a.cholesky(FillOrdering::MinimumDegree).unwrap().l().nnz();
+        assert!(nnz < 250_000, "{} entries in L", nnz);
+    }
+}
@@ -0,0 +1,66 @@
+use super::{csc::CscMatrix, csr::CsrMatrix};
+use crate::scalar::Scalar;
+use rayon::prelude::*;
+
+impl<T> CsrMatrix<T>
+where
+    T: Sync,
+{
+    /// Returns a parallel iterator over the column indices and values of each row.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (&[usize], &[T])> {
+        (0..self.nrows()).into_par_iter().map(|i| self.0.lane(i))
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Scalar + Send + Sync,
+{
+    /// Parallel counterpart of [`mul_vec`](Self::mul_vec), computing the rows
+    /// of the product on separate threads.
+    pub fn par_mul_vec(&self, x: &[T]) -> Vec<T> {
+        self.check_len(x);
+
+        (0..self.nrows())
+            .into_par_iter()
+            .map(|i| self.row_dot(i, x))
+            .collect()
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Sync,
+{
+    /// Returns a parallel iterator over the row indices and values of each column.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (&[usize], &[T])> {
+        (0..self.ncols()).into_par_iter().map(|j| self.0.lane(j))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn par_iter() {
+        let mat = Matrix::from_rows([[1, 0, 2], [0, 0, 3], [4, 5, 0]]);
+        let csr = CsrMatrix::from(mat.clone());
+        let csc = CscMatrix::from(mat);
+
+        let rows: Vec<i32> = csr.par_iter().map(|(_, v)| v.iter().sum()).collect();
+        assert_eq!(vec![3, 3, 9], rows);
+
+        let cols: Vec<Vec<usize>> = csc.par_iter().map(|(i, _)| i.to_vec()).collect();
+        assert_eq!(vec![vec![0, 2], vec![2], vec![0, 1]], cols);
+    }
+
+    #[test]
+    fn par_mul_vec() {
+        let csr = CsrMatrix::from(Matrix::from_rows([[1, 0, 2], [0, 0, 3], [4, 5, 0]]));
+
+        assert_eq!(csr.mul_vec(&[1, 2, 3]), csr.par_mul_vec(&[1, 2, 3]));
+        assert_eq!(vec![7, 9, 14], csr.par_mul_vec(&[1, 2, 3]));
+    }
+}
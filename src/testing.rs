@@ -0,0 +1,80 @@
+//! Fixtures and assertions shared by the unit tests.
+
+use crate::{dmatrix::DMatrix, matrix::Matrix, sparse::coo::CooMatrix};
+
+/// Asserts that every entry of `actual` is within `tol` of `expected`, scaled
+/// by the magnitude of the expected entry once it exceeds one.
+pub fn assert_approx<const M: usize, const N: usize>(
+    expected: Matrix<f64, M, N>,
+    actual: Matrix<f64, M, N>,
+    tol: f64,
+) {
+    expected
+        .iter()
+        .zip(actual.iter())
+        .for_each(|(x, y)| assert!((x - y).abs() <= tol * x.abs().max(1.0), "{} != {}", x, y));
+}
+
+/// Fills a matrix with small integers in `-11..=11` that follow no obvious
+/// pattern, so that different `seed`s give unrelated matrices.
+pub fn sample<T, const M: usize, const N: usize>(seed: usize) -> Matrix<T, M, N>
+where
+    T: From<i32>,
+{
+    Matrix::from_rows(std::array::from_fn(|i| {
+        std::array::from_fn(|j| entry(i, j, seed))
+    }))
+}
+
+/// Dynamically sized counterpart of [`sample`].
+pub fn sample_dyn<T>(nrows: usize, ncols: usize, seed: usize) -> DMatrix<T>
+where
+    T: From<i32>,
+{
+    DMatrix::from_fn(nrows, ncols, |i, j| entry(i, j, seed))
+}
+
+fn entry<T>(i: usize, j: usize, seed: usize) -> T
+where
+    T: From<i32>,
+{
+    T::from(((i * 31 + j * 17 + seed) % 23) as i32 - 11)
+}
+
+/// Builds the 5-point Laplacian on a `size` by `size` grid.
+pub fn poisson(size: usize) -> CooMatrix<f64> {
+    stencil(size, [-1.0, -1.0, -1.0, -1.0])
+}
+
+/// Builds an upwinded convection-diffusion stencil on a `size` by `size`
+/// grid, which is diagonally dominant but not symmetric.
+pub fn convection_diffusion(size: usize) -> CooMatrix<f64> {
+    stencil(size, [-1.5, -0.5, -1.2, -0.8])
+}
+
+/// Couples each grid point to its north, south, west and east neighbours
+/// with the given weights around a diagonal of 4.
+fn stencil(size: usize, [north, south, west, east]: [f64; 4]) -> CooMatrix<f64> {
+    let n = size * size;
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..size {
+        for j in 0..size {
+            let k = i * size + j;
+            coo.push(k, k, 4.0);
+            if i > 0 {
+                coo.push(k, k - size, north);
+            }
+            if i + 1 < size {
+                coo.push(k, k + size, south);
+            }
+            if j > 0 {
+                coo.push(k, k - 1, west);
+            }
+            if j + 1 < size {
+                coo.push(k, k + 1, east);
+            }
+        }
+    }
+
+    coo
+}
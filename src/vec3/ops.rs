@@ -7,6 +7,25 @@ impl Vec3 {
         self.dot(self).sqrt()
     }
 
+    pub fn len_1(self) -> f32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn len_inf(self) -> f32 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    /// Returns the `p`-norm, of which [`len`](Self::len) is the case `p = 2`.
+    pub fn len_p(self, p: f32) -> f32 {
+        assert!(p >= 1.0, "cannot take the {}-norm of a vector", p);
+
+        if p == f32::INFINITY {
+            self.len_inf()
+        } else {
+            (self.x.abs().powf(p) + self.y.abs().powf(p) + self.z.abs().powf(p)).powf(p.recip())
+        }
+    }
+
     pub fn ang(self, other: Self) -> f32 {
         (self.dot(other) / (self.dot(self) * other.dot(other)).sqrt()).acos()
     }
@@ -150,6 +169,24 @@ mod tests {
         assert_eq!(13.0, v.len());
     }
 
+    #[test]
+    fn len_p() {
+        let v = Vec3::new(3.0, -4.0, 12.0);
+
+        assert_eq!(19.0, v.len_1());
+        assert_eq!(12.0, v.len_inf());
+        assert_eq!(19.0, v.len_p(1.0));
+        assert!((13.0 - v.len_p(2.0)).abs() < 1e-5);
+        assert_eq!(12.0, v.len_p(f32::INFINITY));
+        assert!(v.len_p(3.0) < v.len_p(2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take the 0.5-norm of a vector")]
+    fn len_p_below_one() {
+        let _ = Vec3::new(3.0, -4.0, 12.0).len_p(0.5);
+    }
+
     #[test]
     fn ang() {
         let v1 = Vec3::I;